@@ -0,0 +1,139 @@
+use crate::admin::model::{AdminAction, AdminMsg};
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, Message};
+use crate::ui;
+use async_trait::async_trait;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct AdminScreen {
+    sender: Sender<Message>,
+}
+
+impl AdminScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        AdminScreen { sender }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for AdminScreen {
+    fn scope(&self) -> Scope {
+        Scope::Admin
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.admin_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title =
+            ui::widgets::title_paragraph("Wire Mock Inspector - Server Housekeeping", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_layout[1]);
+
+        // Actions (left side)
+        let items: Vec<ListItem> = AdminAction::ALL
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                let warning = if action.is_destructive() { "!" } else { " " };
+                if i == model.selected_action_index {
+                    ListItem::new(format!("▶{} {}", warning, action.description()))
+                        .style(app.theme.selected())
+                } else {
+                    ListItem::new(format!(" {} {}", warning, action.description()))
+                        .style(app.theme.text())
+                }
+            })
+            .collect();
+        let server = model.selected_server_url.as_deref().unwrap_or("no server");
+        let actions = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Actions on {}", server)),
+        );
+        frame.render_widget(actions, content_layout[0]);
+
+        // Results (right side), newest first
+        let results: Vec<ListItem> = model
+            .results
+            .iter()
+            .rev()
+            .map(|(action, result)| match result {
+                Ok(()) => {
+                    ListItem::new(format!("✔ {}", action.description())).style(app.theme.success())
+                }
+                Err(err) => ListItem::new(format!("✘ {}: {}", action.description(), err))
+                    .style(app.theme.error()),
+            })
+            .collect();
+        let results =
+            List::new(results).block(Block::default().borders(Borders::ALL).title("Results"));
+        frame.render_widget(results, content_layout[1]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Admin, &[]);
+        ui::widgets::render_commands(frame, main_layout[2], &commands);
+
+        // Confirmation
+        if let Some(action) = model.pending_confirmation {
+            let area = ui::widgets::centered_rect(50, 6, frame.area());
+            let confirmation = Paragraph::new(format!(
+                "{} on {}?\n\ny: Confirm    Esc: Cancel",
+                action.description(),
+                server
+            ))
+            .wrap(Wrap { trim: false })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(app.theme.error())
+                    .title("Confirm"),
+            );
+            frame.render_widget(Clear, area);
+            frame.render_widget(confirmation, area);
+        }
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender.send(Message::Admin(AdminMsg::Back))?;
+                Ok(())
+            }
+            Action::Confirm => {
+                self.sender.send(Message::Admin(AdminMsg::Confirm))?;
+                Ok(())
+            }
+            Action::Run => {
+                self.sender
+                    .send(Message::Admin(AdminMsg::RunSelectedRequested))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender.send(Message::Admin(AdminMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Admin(AdminMsg::SelectNext))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
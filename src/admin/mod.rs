@@ -0,0 +1,2 @@
+pub mod admin_screen;
+pub mod model;
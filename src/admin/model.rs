@@ -0,0 +1,201 @@
+use crate::model::{Command, GlobalMsg, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::stub::model::StubMsg;
+use crate::wire_mock;
+use async_trait::async_trait;
+use std::error::Error;
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AdminAction {
+    Reset,
+    ResetMappings,
+    SaveMappings,
+    ClearJournal,
+    ResetScenarios,
+    Shutdown,
+}
+
+impl AdminAction {
+    pub const ALL: [AdminAction; 6] = [
+        AdminAction::Reset,
+        AdminAction::ResetMappings,
+        AdminAction::SaveMappings,
+        AdminAction::ClearJournal,
+        AdminAction::ResetScenarios,
+        AdminAction::Shutdown,
+    ];
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            AdminAction::Reset => "Full reset (stubs, journal and scenarios)",
+            AdminAction::ResetMappings => "Reset mappings to on-disk defaults",
+            AdminAction::SaveMappings => "Persist mappings to disk",
+            AdminAction::ClearJournal => "Clear the request journal",
+            AdminAction::ResetScenarios => "Reset all scenarios to Started",
+            AdminAction::Shutdown => "Shut down the server",
+        }
+    }
+
+    /// Whether the action throws away server state and needs a confirmation.
+    pub fn is_destructive(&self) -> bool {
+        *self != AdminAction::SaveMappings
+    }
+
+    fn run(&self, server_url: &str) -> Result<(), Box<dyn Error>> {
+        match self {
+            AdminAction::Reset => wire_mock::client::reset(server_url),
+            AdminAction::ResetMappings => wire_mock::client::reset_mappings(server_url),
+            AdminAction::SaveMappings => wire_mock::client::save_mappings(server_url),
+            AdminAction::ClearJournal => wire_mock::client::clear_requests(server_url),
+            AdminAction::ResetScenarios => wire_mock::client::reset_scenarios(server_url),
+            AdminAction::Shutdown => wire_mock::client::shutdown(server_url),
+        }
+    }
+}
+
+pub struct AdminModel {
+    pub selected_server_url: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
+    /// Whether the housekeeping screen is shown. Requests from elsewhere,
+    /// such as the command palette after a refused switch, are dropped.
+    pub active: bool,
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub selected_action_index: usize,
+    /// Destructive action waiting for the user to confirm it.
+    pub pending_confirmation: Option<AdminAction>,
+    /// Outcome of each action run in this session, newest last.
+    pub results: Vec<(AdminAction, Result<(), String>)>,
+}
+
+#[async_trait]
+impl ModelTrait<AdminMsg, AdminCommand> for AdminModel {
+    async fn apply_event(&mut self, event: AdminMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            AdminMsg::SelectNext => {
+                if self.pending_confirmation.is_none() {
+                    self.selected_action_index =
+                        (self.selected_action_index + 1).min(AdminAction::ALL.len() - 1);
+                }
+                Ok(())
+            }
+            AdminMsg::SelectPrevious => {
+                if self.pending_confirmation.is_none() {
+                    self.selected_action_index = self.selected_action_index.saturating_sub(1);
+                }
+                Ok(())
+            }
+            AdminMsg::RunSelectedRequested => {
+                self.request(AdminAction::ALL[self.selected_action_index])
+            }
+            AdminMsg::Request(action) => {
+                if self.active && self.pending_confirmation.is_none() {
+                    self.selected_action_index = AdminAction::ALL
+                        .iter()
+                        .position(|a| *a == action)
+                        .unwrap_or(self.selected_action_index);
+                    self.request(action)?;
+                }
+                Ok(())
+            }
+            AdminMsg::Confirm => {
+                if let Some(action) = self.pending_confirmation.take() {
+                    self.command_sender
+                        .send(Command::Admin(AdminCommand::Run(action)))?;
+                }
+                Ok(())
+            }
+            AdminMsg::Back => {
+                if self.pending_confirmation.take().is_none() {
+                    self.event_sender
+                        .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: AdminCommand) -> Result<(), Box<dyn Error>> {
+        match command {
+            AdminCommand::Run(action) => {
+                let result = match &self.selected_server_url {
+                    Some(server_url) => {
+                        match self.read_only_servers.check(server_url, "housekeeping") {
+                            Ok(()) => action.run(server_url).map_err(|e| e.to_string()),
+                            Err(err) => Err(err.to_string()),
+                        }
+                    }
+                    None => Err(AdminError::NoServerSelected.to_string()),
+                };
+                if result.is_ok() && action != AdminAction::Shutdown {
+                    self.event_sender
+                        .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+                }
+                self.results.push((action, result));
+                Ok(())
+            }
+        }
+    }
+}
+
+impl AdminModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            selected_server_url: None,
+            read_only_servers,
+            active: false,
+            event_sender,
+            command_sender,
+            selected_action_index: 0,
+            pending_confirmation: None,
+            results: vec![],
+        }
+    }
+
+    /// Called whenever the screen changes. Leaving the screen drops a
+    /// confirmation nobody can see any more.
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+        if !active {
+            self.pending_confirmation = None;
+        }
+    }
+
+    fn request(&mut self, action: AdminAction) -> Result<(), Box<dyn Error>> {
+        if action.is_destructive() {
+            self.pending_confirmation = Some(action);
+        } else {
+            self.command_sender
+                .send(Command::Admin(AdminCommand::Run(action)))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum AdminCommand {
+    Run(AdminAction),
+}
+
+#[derive(Clone, Debug)]
+pub enum AdminMsg {
+    SelectNext,
+    SelectPrevious,
+    RunSelectedRequested,
+    /// Selects the action and runs it, asking first when it is destructive.
+    Request(AdminAction),
+    Confirm,
+    Back,
+}
+
+#[derive(Error, Debug)]
+pub enum AdminError {
+    #[error("No server selected")]
+    NoServerSelected,
+}
@@ -0,0 +1,179 @@
+use crate::model::{Message, ModelTrait};
+use crate::ui::notification;
+use crate::ui::notification::DesktopNotification;
+use crate::wire_mock;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::error::Error;
+use std::time::Duration;
+use tokio::sync::broadcast::Sender;
+use tokio::time::interval;
+
+/// How often the selected server is asked for unmatched requests.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// A check gives up after this long, so a dead server never piles them up.
+const POLL_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub struct AlertModel {
+    pub server_url: Option<String>,
+    pub event_sender: Sender<Message>,
+    pub poll_task: Option<tokio::task::JoinHandle<()>>,
+    /// Whether a check is waiting for the server, so checks never overlap.
+    pub checking: bool,
+    /// Time the newest unmatched request already looked at was logged, per
+    /// server.
+    pub last_seen_dates: HashMap<String, i64>,
+    /// Unmatched requests that arrived since the journal was last opened.
+    pub new_unmatched: usize,
+    pub desktop_notification: DesktopNotification,
+    pub status_message: Option<String>,
+}
+
+#[async_trait]
+impl ModelTrait<AlertMsg, AlertCommand> for AlertModel {
+    async fn apply_event(&mut self, event: AlertMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            AlertMsg::WatchServer(server_url) => {
+                if self.server_url != server_url {
+                    self.new_unmatched = 0;
+                }
+                self.server_url = server_url;
+                self.start_polling();
+                Ok(())
+            }
+            AlertMsg::PollRequested => {
+                self.check_unmatched();
+                Ok(())
+            }
+            AlertMsg::UnmatchedChecked(server_url, logged_dates) => {
+                self.checking = false;
+                // A server that is down or has the journal disabled is not worth an alert
+                let Ok(logged_dates) = logged_dates else {
+                    return Ok(());
+                };
+                let count = self.count_new(&server_url, &logged_dates);
+                if count > 0 && self.server_url.as_ref() == Some(&server_url) {
+                    self.new_unmatched += count;
+                    let message = format!("{} new unmatched request(s)", count);
+                    if let Err(err) =
+                        notification::notify(self.desktop_notification, "WireMock", &message)
+                    {
+                        self.status_message = Some(format!("Notification failed: {}", err));
+                    }
+                }
+                Ok(())
+            }
+            AlertMsg::Acknowledge => {
+                self.new_unmatched = 0;
+                Ok(())
+            }
+            AlertMsg::CycleDesktopNotification => {
+                self.desktop_notification = self.desktop_notification.next();
+                self.status_message = Some(format!(
+                    "Unmatched request alerts: {}",
+                    self.desktop_notification.description()
+                ));
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, _: AlertCommand) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
+
+impl AlertModel {
+    pub fn new(event_sender: Sender<Message>) -> Self {
+        Self {
+            server_url: None,
+            event_sender,
+            poll_task: None,
+            checking: false,
+            last_seen_dates: HashMap::new(),
+            new_unmatched: 0,
+            desktop_notification: DesktopNotification::Off,
+            status_message: None,
+        }
+    }
+
+    pub fn status_text(&self) -> Option<String> {
+        (self.new_unmatched > 0).then(|| format!("⚑ {} new unmatched", self.new_unmatched))
+    }
+
+    fn start_polling(&mut self) {
+        if self.poll_task.is_some() {
+            return;
+        }
+        let sender = self.event_sender.clone();
+        let task = tokio::spawn(async move {
+            let mut interval = interval(POLL_INTERVAL);
+            loop {
+                interval.tick().await;
+                if sender
+                    .send(Message::Alert(AlertMsg::PollRequested))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+        self.poll_task = Some(task);
+    }
+
+    /// Asks the server for its unmatched requests in the background, only
+    /// downloading those rather than the whole journal.
+    fn check_unmatched(&mut self) {
+        let Some(server_url) = self.server_url.clone() else {
+            return;
+        };
+        if self.checking {
+            return;
+        }
+        self.checking = true;
+        let sender = self.event_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let logged_dates = wire_mock::client::get_unmatched_requests(&server_url, POLL_TIMEOUT)
+                .map(|unmatched| {
+                    unmatched
+                        .requests
+                        .iter()
+                        .filter_map(|request| request.logged_date)
+                        .collect()
+                })
+                .map_err(|err| err.to_string());
+            let _ = sender.send(Message::Alert(AlertMsg::UnmatchedChecked(
+                server_url,
+                logged_dates,
+            )));
+        });
+    }
+
+    /// Counts unmatched requests logged after the newest one already seen.
+    /// The first check of a server only records where the journal stands.
+    fn count_new(&mut self, server_url: &str, logged_dates: &[i64]) -> usize {
+        let newest = logged_dates.iter().copied().max().unwrap_or(i64::MIN);
+        let Some(last_seen) = self.last_seen_dates.insert(server_url.to_string(), newest) else {
+            return 0;
+        };
+        logged_dates
+            .iter()
+            .filter(|&&date| date > last_seen)
+            .count()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum AlertCommand {}
+
+#[derive(Clone, Debug)]
+pub enum AlertMsg {
+    WatchServer(Option<String>),
+    PollRequested,
+    /// Logged dates of the server's unmatched requests, or why they could
+    /// not be read.
+    UnmatchedChecked(String, Result<Vec<i64>, String>),
+    Acknowledge,
+    CycleDesktopNotification,
+}
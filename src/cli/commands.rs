@@ -0,0 +1,175 @@
+use crate::cli::output;
+use crate::cli::{CliCommand, CliError, OutputFormat, RequestsCommand, StubsCommand};
+use crate::import::files;
+use crate::server::model::ServerConfiguration;
+use crate::wire_mock;
+use crate::wire_mock::client::{ServeEvent, StubMapping, StubMappings};
+use std::error::Error;
+use std::fs;
+
+/// Runs a one-shot command against the server given on the command line, or
+/// else the selected server of the configuration.
+pub fn run(
+    command: CliCommand,
+    format: OutputFormat,
+    configuration: &ServerConfiguration,
+    server: Option<String>,
+    read_only: bool,
+) -> Result<(), Box<dyn Error>> {
+    let server_url = match server {
+        Some(server) => server,
+        None => configuration
+            .selected_server()
+            .ok_or(CliError::NoServerConfigured)?
+            .url
+            .clone(),
+    };
+    let read_only = read_only || configuration.is_read_only(&server_url);
+    if read_only && is_mutating(&command) {
+        return Err(CliError::ReadOnly(server_url).into());
+    }
+    match command {
+        CliCommand::Stubs(command) => run_stubs(&server_url, command, format),
+        CliCommand::Requests(command) => run_requests(&server_url, command, format),
+        CliCommand::Reset => {
+            wire_mock::client::reset(&server_url)?;
+            output::print_done(format, &format!("Reset {}", server_url))
+        }
+    }
+}
+
+fn is_mutating(command: &CliCommand) -> bool {
+    match command {
+        CliCommand::Stubs(command) => matches!(
+            command,
+            StubsCommand::Delete { .. } | StubsCommand::Import { .. }
+        ),
+        CliCommand::Requests(command) => matches!(command, RequestsCommand::Clear),
+        CliCommand::Reset => true,
+    }
+}
+
+fn run_stubs(
+    server_url: &str,
+    command: StubsCommand,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        StubsCommand::List => {
+            let stubs = wire_mock::client::get_all_stubs(server_url)?;
+            let rows = stubs.mappings.iter().map(stub_row).collect();
+            output::print(
+                format,
+                &stubs,
+                &["ID", "PRIORITY", "METHOD", "URL", "STATUS"],
+                rows,
+            )
+        }
+        StubsCommand::Get { id } => {
+            let stub = wire_mock::client::get_stub(server_url, &id)?
+                .ok_or_else(|| CliError::StubNotFound(id.clone()))?;
+            output::print(
+                format,
+                &stub,
+                &["ID", "PRIORITY", "METHOD", "URL", "STATUS"],
+                vec![stub_row(&stub)],
+            )
+        }
+        StubsCommand::Delete { id } => {
+            wire_mock::client::delete_stub(server_url, &id)?;
+            output::print_done(format, &format!("Deleted stub {}", id))
+        }
+        StubsCommand::Import { path, policy } => {
+            let mappings: Vec<StubMapping> = files::load_mappings(&path)?
+                .into_iter()
+                .map(|(_, mapping)| mapping)
+                .collect();
+            wire_mock::client::import_stubs(server_url, &mappings, policy.into())?;
+            output::print_done(
+                format,
+                &format!("Imported {} stubs from {}", mappings.len(), path.display()),
+            )
+        }
+        StubsCommand::Export { dir: None } => {
+            let stubs = wire_mock::client::get_all_stubs(server_url)?;
+            // An export is always JSON so it can be imported again
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&StubMappings {
+                    mappings: stubs.mappings,
+                    meta: None,
+                })?
+            );
+            Ok(())
+        }
+        StubsCommand::Export { dir: Some(dir) } => {
+            let stubs = wire_mock::client::get_all_stubs(server_url)?.mappings;
+            fs::create_dir_all(&dir)?;
+            for stub in &stubs {
+                fs::write(
+                    dir.join(format!("{}.json", stub.id)),
+                    serde_json::to_string_pretty(stub)?,
+                )?;
+            }
+            output::print_done(
+                format,
+                &format!("Exported {} stubs to {}", stubs.len(), dir.display()),
+            )
+        }
+    }
+}
+
+fn run_requests(
+    server_url: &str,
+    command: RequestsCommand,
+    format: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let unmatched_only = match command {
+        RequestsCommand::Clear => {
+            wire_mock::client::clear_requests(server_url)?;
+            return output::print_done(format, "Cleared the request journal");
+        }
+        RequestsCommand::List => false,
+        RequestsCommand::Unmatched => true,
+    };
+    let requests: Vec<ServeEvent> = wire_mock::client::get_requests(server_url)?
+        .requests
+        .into_iter()
+        .filter(|r| !unmatched_only || !r.was_matched)
+        .collect();
+    let rows = requests
+        .iter()
+        .map(|event| {
+            vec![
+                event.request.logged_date_string.clone().unwrap_or_default(),
+                event.request.method.clone(),
+                event.request.url.clone(),
+                event
+                    .response
+                    .as_ref()
+                    .map(|r| r.status.to_string())
+                    .unwrap_or_default(),
+                event.matched_stub_id().unwrap_or("-").to_string(),
+            ]
+        })
+        .collect();
+    output::print(
+        format,
+        &requests,
+        &["LOGGED", "METHOD", "URL", "STATUS", "STUB"],
+        rows,
+    )
+}
+
+fn stub_row(stub: &StubMapping) -> Vec<String> {
+    vec![
+        stub.id.clone(),
+        stub.priority.map(|p| p.to_string()).unwrap_or_default(),
+        stub.request.method.clone(),
+        stub.request
+            .display_url()
+            .unwrap_or("(any url)")
+            .to_string(),
+        stub.response.status.to_string(),
+    ]
+}
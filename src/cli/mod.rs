@@ -0,0 +1,124 @@
+pub mod commands;
+pub mod output;
+
+use crate::server::model::ServerConfiguration;
+use crate::wire_mock::client::DuplicatePolicy;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Terminal UI for WireMock. Without a subcommand the interactive UI starts;
+/// with one, the command runs once against the configured server and exits.
+#[derive(Parser, Debug)]
+#[command(name = "wm-tui", version)]
+pub struct Cli {
+    /// Server URL to open directly instead of the server selection.
+    #[arg(long, global = true, env = "WM_TUI_SERVER")]
+    pub server: Option<String>,
+    /// Named server profile to open directly; takes precedence over --server.
+    #[arg(long, global = true)]
+    pub profile: Option<String>,
+    /// Server configuration file to use instead of the default location.
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+    /// Refuse every change to the server.
+    #[arg(long, global = true)]
+    pub read_only: bool,
+    /// Output format of one-shot commands.
+    #[arg(long, value_enum, global = true, default_value_t = OutputFormat::Table)]
+    pub output: OutputFormat,
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+}
+
+impl Cli {
+    /// The server chosen on the command line or in the environment, if any.
+    pub fn startup_server(
+        &self,
+        configuration: &ServerConfiguration,
+    ) -> Result<Option<String>, CliError> {
+        if let Some(name) = &self.profile {
+            return configuration
+                .find_profile(name)
+                .map(|profile| Some(profile.url.clone()))
+                .ok_or_else(|| CliError::UnknownProfile(name.clone()));
+        }
+        Ok(self.server.clone())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    Json,
+    Table,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CliCommand {
+    /// Manage stub mappings.
+    #[command(subcommand)]
+    Stubs(StubsCommand),
+    /// Inspect or clear the request journal.
+    #[command(subcommand)]
+    Requests(RequestsCommand),
+    /// Remove all stubs, journal entries and scenario state.
+    Reset,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum StubsCommand {
+    /// List all stub mappings.
+    List,
+    /// Show one stub mapping.
+    Get { id: String },
+    /// Delete one stub mapping.
+    Delete { id: String },
+    /// Import a mapping file or every `*.json` file below a directory.
+    Import {
+        path: PathBuf,
+        #[arg(long, value_enum, default_value_t = Policy::Overwrite)]
+        policy: Policy,
+    },
+    /// Print all mappings as JSON, or write one file per stub into a directory.
+    Export {
+        #[arg(long)]
+        dir: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum RequestsCommand {
+    /// List all logged requests, newest first.
+    List,
+    /// List logged requests that no stub matched.
+    Unmatched,
+    /// Clear the request journal.
+    Clear,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Policy {
+    Overwrite,
+    Ignore,
+}
+
+impl From<Policy> for DuplicatePolicy {
+    fn from(policy: Policy) -> Self {
+        match policy {
+            Policy::Overwrite => DuplicatePolicy::Overwrite,
+            Policy::Ignore => DuplicatePolicy::Ignore,
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum CliError {
+    #[error("No server configured")]
+    NoServerConfigured,
+    #[error("No server profile named {0}")]
+    UnknownProfile(String),
+    #[error("Stub {0} not found")]
+    StubNotFound(String),
+    #[error("Refusing to change {0}: the server is read-only")]
+    ReadOnly(String),
+}
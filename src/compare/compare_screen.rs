@@ -0,0 +1,216 @@
+use crate::compare::model::{diff_rows, CompareMsg, DiffKind, SyncDirection};
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, Message};
+use crate::ui;
+use async_trait::async_trait;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::{Line, Modifier, Style};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use similar::ChangeTag;
+use tokio::sync::broadcast::Sender;
+
+pub struct CompareScreen {
+    sender: Sender<Message>,
+}
+
+impl CompareScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        CompareScreen { sender }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for CompareScreen {
+    fn scope(&self) -> Scope {
+        Scope::Compare
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.compare_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock - Compare Servers", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        // Selected servers
+        let server_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_layout[1]);
+        let none = "(none)".to_string();
+        let left = Paragraph::new(model.left_server().unwrap_or(&none).as_str())
+            .block(Block::default().borders(Borders::ALL).title("1: Left"));
+        let right = Paragraph::new(model.right_server().unwrap_or(&none).as_str())
+            .block(Block::default().borders(Borders::ALL).title("2: Right"));
+        frame.render_widget(left, server_layout[0]);
+        frame.render_widget(right, server_layout[1]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(30), Constraint::Percentage(70)])
+            .split(main_layout[2]);
+
+        // Differences (left side)
+        let items: Vec<ListItem> = model
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let (badge, color) = match entry.kind {
+                    DiffKind::OnlyLeft => ("<  ", app.theme.error),
+                    DiffKind::OnlyRight => ("  >", app.theme.success),
+                    DiffKind::Changed => ("<->", app.theme.highlight),
+                };
+                let request = &entry.request().request;
+                let url = request.display_url().unwrap_or("(no url)");
+                let marker = if i == model.selected_entry_index {
+                    "▶"
+                } else {
+                    " "
+                };
+                let mut style = Style::default().fg(color);
+                if i == model.selected_entry_index {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                ListItem::new(format!("{} {} {} {}", marker, badge, request.method, url))
+                    .style(style)
+            })
+            .collect();
+        let entry_list =
+            List::new(items).block(Block::default().borders(Borders::ALL).title("Differences"));
+        frame.render_widget(entry_list, content_layout[0]);
+
+        // Side-by-side diff (right side)
+        let diff_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(content_layout[1]);
+        let rows = model.selected_entry().map(diff_rows).unwrap_or_default();
+        let side = |pick_left: bool| -> Vec<Line> {
+            rows.iter()
+                .map(|row| {
+                    let text = if pick_left { &row.left } else { &row.right };
+                    let style = match (row.tag, pick_left) {
+                        (ChangeTag::Delete, true) => app.theme.error(),
+                        (ChangeTag::Insert, false) => app.theme.success(),
+                        _ => app.theme.text(),
+                    };
+                    Line::styled(text.clone().unwrap_or_default(), style)
+                })
+                .collect()
+        };
+        let scroll = (model.scroll_offset as u16, 0);
+        let left_diff = Paragraph::new(side(true))
+            .block(Block::default().borders(Borders::ALL).title("Left"))
+            .scroll(scroll);
+        let right_diff = Paragraph::new(side(false))
+            .block(Block::default().borders(Borders::ALL).title("Right"))
+            .scroll(scroll);
+        frame.render_widget(left_diff, diff_layout[0]);
+        frame.render_widget(right_diff, diff_layout[1]);
+
+        // Status
+        let status = Paragraph::new(model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Compare, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+
+        // Confirmation
+        if let Some(change) = model
+            .pending_confirmation
+            .and_then(|d| model.describe_sync(d))
+        {
+            let area = ui::widgets::centered_rect(50, 6, frame.area());
+            let confirmation = Paragraph::new(format!("{}?\n\ny: Confirm    Esc: Cancel", change))
+                .wrap(Wrap { trim: false })
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(app.theme.error())
+                        .title("Confirm"),
+                );
+            frame.render_widget(Clear, area);
+            frame.render_widget(confirmation, area);
+        }
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Back => {
+                self.sender.send(Message::Compare(CompareMsg::Back))?;
+                Ok(())
+            }
+            Action::Confirm => {
+                self.sender.send(Message::Compare(CompareMsg::Confirm))?;
+                Ok(())
+            }
+            Action::PickLeftServer => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::CycleLeftServer))?;
+                Ok(())
+            }
+            Action::PickRightServer => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::CycleRightServer))?;
+                Ok(())
+            }
+            Action::Compare => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::CompareRequested))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Compare(CompareMsg::SelectNext))?;
+                Ok(())
+            }
+            Action::ScrollUp => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::ScrollDiffUp))?;
+                Ok(())
+            }
+            Action::ScrollDown => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::ScrollDiffDown))?;
+                Ok(())
+            }
+            Action::SyncToRight => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::SyncSelectedRequested(
+                        SyncDirection::LeftToRight,
+                    )))?;
+                Ok(())
+            }
+            Action::SyncToLeft => {
+                self.sender
+                    .send(Message::Compare(CompareMsg::SyncSelectedRequested(
+                        SyncDirection::RightToLeft,
+                    )))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
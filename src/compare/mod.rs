@@ -0,0 +1,2 @@
+pub mod compare_screen;
+pub mod model;
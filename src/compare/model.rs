@@ -0,0 +1,489 @@
+use crate::model::{Command, GlobalMsg, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::wire_mock;
+use crate::wire_mock::client::StubMapping;
+use async_trait::async_trait;
+use similar::{ChangeTag, TextDiff};
+use std::error::Error;
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DiffKind {
+    OnlyLeft,
+    OnlyRight,
+    Changed,
+}
+
+#[derive(Clone, Debug)]
+pub struct CompareEntry {
+    pub kind: DiffKind,
+    pub left: Option<StubMapping>,
+    pub right: Option<StubMapping>,
+}
+
+impl CompareEntry {
+    pub fn request(&self) -> &StubMapping {
+        self.left
+            .as_ref()
+            .or(self.right.as_ref())
+            .expect("compare entry without any stub")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SyncDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// One row of the side-by-side JSON diff.
+pub struct DiffRow {
+    pub left: Option<String>,
+    pub right: Option<String>,
+    pub tag: ChangeTag,
+}
+
+pub struct CompareModel {
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub server_list: Vec<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub left_server_index: usize,
+    pub right_server_index: usize,
+    pub entries: Vec<CompareEntry>,
+    pub identical_count: usize,
+    pub selected_entry_index: usize,
+    pub scroll_offset: usize,
+    /// Sync of the selected entry waiting for the user to confirm it.
+    pub pending_confirmation: Option<SyncDirection>,
+    pub status_message: Option<String>,
+}
+
+#[async_trait]
+impl ModelTrait<CompareMsg, CompareCommand> for CompareModel {
+    async fn apply_event(&mut self, event: CompareMsg) -> Result<(), Box<dyn Error>> {
+        // The confirmation is about the selected entry, so nothing else may change
+        // until it is answered
+        if self.pending_confirmation.is_some()
+            && !matches!(event, CompareMsg::Confirm | CompareMsg::Back)
+        {
+            return Ok(());
+        }
+        match event {
+            CompareMsg::CycleLeftServer => {
+                self.left_server_index = self.next_server_index(self.left_server_index);
+                self.clear_result();
+                Ok(())
+            }
+            CompareMsg::CycleRightServer => {
+                self.right_server_index = self.next_server_index(self.right_server_index);
+                self.clear_result();
+                Ok(())
+            }
+            CompareMsg::SelectNext => {
+                if !self.entries.is_empty() {
+                    self.selected_entry_index =
+                        (self.selected_entry_index + 1).min(self.entries.len() - 1);
+                }
+                self.scroll_offset = 0;
+                Ok(())
+            }
+            CompareMsg::SelectPrevious => {
+                self.selected_entry_index = self.selected_entry_index.saturating_sub(1);
+                self.scroll_offset = 0;
+                Ok(())
+            }
+            CompareMsg::ScrollDiffUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                Ok(())
+            }
+            CompareMsg::ScrollDiffDown => {
+                self.scroll_offset += 1;
+                Ok(())
+            }
+            CompareMsg::CompareRequested => {
+                self.command_sender
+                    .send(Command::Compare(CompareCommand::LoadAndCompare))?;
+                Ok(())
+            }
+            CompareMsg::SyncSelectedRequested(direction) => {
+                if self.selected_entry().is_some() {
+                    self.pending_confirmation = Some(direction);
+                }
+                Ok(())
+            }
+            CompareMsg::Confirm => {
+                if let Some(direction) = self.pending_confirmation.take() {
+                    self.command_sender
+                        .send(Command::Compare(CompareCommand::SyncSelected(direction)))?;
+                }
+                Ok(())
+            }
+            CompareMsg::Back => {
+                if self.pending_confirmation.take().is_none() {
+                    self.event_sender
+                        .send(Message::Global(GlobalMsg::SwitchToServerSelectionScreen))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: CompareCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            CompareCommand::LoadAndCompare => self.load_and_compare(),
+            CompareCommand::SyncSelected(direction) => self.sync_selected(direction),
+        };
+        if let Err(err) = result {
+            self.status_message = Some(err.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl CompareModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            event_sender,
+            command_sender,
+            server_list: vec![],
+            read_only_servers,
+            left_server_index: 0,
+            right_server_index: 0,
+            entries: vec![],
+            identical_count: 0,
+            selected_entry_index: 0,
+            scroll_offset: 0,
+            pending_confirmation: None,
+            status_message: None,
+        }
+    }
+
+    /// Starts a comparison of the given server against the next one in the list.
+    pub fn reset(&mut self, server_list: Vec<String>, left_server_index: usize) {
+        self.server_list = server_list;
+        self.left_server_index = left_server_index;
+        self.right_server_index = self.next_server_index(left_server_index);
+        self.clear_result();
+    }
+
+    pub fn left_server(&self) -> Option<&String> {
+        self.server_list.get(self.left_server_index)
+    }
+
+    pub fn right_server(&self) -> Option<&String> {
+        self.server_list.get(self.right_server_index)
+    }
+
+    pub fn selected_entry(&self) -> Option<&CompareEntry> {
+        self.entries.get(self.selected_entry_index)
+    }
+
+    fn next_server_index(&self, index: usize) -> usize {
+        if self.server_list.is_empty() {
+            0
+        } else {
+            (index + 1) % self.server_list.len()
+        }
+    }
+
+    fn clear_result(&mut self) {
+        self.entries.clear();
+        self.identical_count = 0;
+        self.selected_entry_index = 0;
+        self.scroll_offset = 0;
+        self.pending_confirmation = None;
+        self.status_message = None;
+    }
+
+    fn servers(&self) -> Result<(String, String), CompareError> {
+        match (self.left_server(), self.right_server()) {
+            (Some(left), Some(right)) if left != right => Ok((left.clone(), right.clone())),
+            (Some(_), Some(_)) => Err(CompareError::SameServer),
+            _ => Err(CompareError::NoServerSelected),
+        }
+    }
+
+    fn load_and_compare(&mut self) -> Result<(), Box<dyn Error>> {
+        let (left_url, right_url) = self.servers()?;
+        let left = wire_mock::client::get_all_stubs(&left_url)?.mappings;
+        let right = wire_mock::client::get_all_stubs(&right_url)?.mappings;
+        let (entries, identical_count) = compare_stubs(left, right);
+        self.entries = entries;
+        self.identical_count = identical_count;
+        self.selected_entry_index = self
+            .selected_entry_index
+            .min(self.entries.len().saturating_sub(1));
+        self.scroll_offset = 0;
+        self.status_message = Some(format!(
+            "{} only left, {} only right, {} changed, {} identical",
+            self.count(DiffKind::OnlyLeft),
+            self.count(DiffKind::OnlyRight),
+            self.count(DiffKind::Changed),
+            self.identical_count
+        ));
+        Ok(())
+    }
+
+    /// What syncing the selected entry in `direction` would do, for the
+    /// confirmation.
+    pub fn describe_sync(&self, direction: SyncDirection) -> Option<String> {
+        let (left_url, right_url) = self.servers().ok()?;
+        let entry = self.selected_entry()?;
+        let (source, target, target_url) = match direction {
+            SyncDirection::LeftToRight => (&entry.left, &entry.right, right_url),
+            SyncDirection::RightToLeft => (&entry.right, &entry.left, left_url),
+        };
+        match (source, target) {
+            (Some(_), None) => Some(format!("Create the stub on {}", target_url)),
+            (Some(_), Some(target)) => {
+                Some(format!("Overwrite stub {} on {}", target.id, target_url))
+            }
+            (None, Some(target)) => Some(format!("Delete stub {} from {}", target.id, target_url)),
+            (None, None) => None,
+        }
+    }
+
+    /// Makes the target side of the selected entry equal to the source side:
+    /// missing stubs are created, changed ones overwritten and extra ones deleted.
+    fn sync_selected(&mut self, direction: SyncDirection) -> Result<(), Box<dyn Error>> {
+        let (left_url, right_url) = self.servers()?;
+        let Some(entry) = self.selected_entry() else {
+            return Ok(());
+        };
+        let (source, target, target_url) = match direction {
+            SyncDirection::LeftToRight => (&entry.left, &entry.right, right_url),
+            SyncDirection::RightToLeft => (&entry.right, &entry.left, left_url),
+        };
+        self.read_only_servers
+            .check(&target_url, &format!("syncing stubs to {}", target_url))?;
+        let summary = match (source, target) {
+            (Some(source), None) => {
+                wire_mock::client::create_stub(&target_url, source)?;
+                format!("Created stub on {}", target_url)
+            }
+            (Some(source), Some(target)) => {
+                let mut mapping = source.clone();
+                mapping.id = target.id.clone();
+                mapping.extra.remove("uuid");
+                wire_mock::client::update_stub(&target_url, &target.id, &mapping)?;
+                format!("Updated stub {} on {}", target.id, target_url)
+            }
+            (None, Some(target)) => {
+                wire_mock::client::delete_stub(&target_url, &target.id)?;
+                format!("Deleted stub {} on {}", target.id, target_url)
+            }
+            (None, None) => return Ok(()),
+        };
+        self.load_and_compare()?;
+        self.status_message = Some(summary);
+        Ok(())
+    }
+
+    fn count(&self, kind: DiffKind) -> usize {
+        self.entries.iter().filter(|e| e.kind == kind).count()
+    }
+}
+
+/// Pairs stubs by id first and then by an equal request pattern. Returns the
+/// differing pairs and the number of identical ones.
+pub fn compare_stubs(
+    left: Vec<StubMapping>,
+    right: Vec<StubMapping>,
+) -> (Vec<CompareEntry>, usize) {
+    let mut right: Vec<Option<StubMapping>> = right.into_iter().map(Some).collect();
+    let mut unmatched_left = vec![];
+    let mut pairs = vec![];
+
+    for stub in left {
+        let position = right
+            .iter()
+            .position(|r| r.as_ref().is_some_and(|r| r.id == stub.id));
+        match position {
+            Some(i) => pairs.push((stub, right[i].take().unwrap())),
+            None => unmatched_left.push(stub),
+        }
+    }
+
+    let mut entries = vec![];
+    for stub in unmatched_left {
+        let position = right
+            .iter()
+            .position(|r| r.as_ref().is_some_and(|r| r.request == stub.request));
+        match position {
+            Some(i) => pairs.push((stub, right[i].take().unwrap())),
+            None => entries.push(CompareEntry {
+                kind: DiffKind::OnlyLeft,
+                left: Some(stub),
+                right: None,
+            }),
+        }
+    }
+
+    let mut identical_count = 0;
+    for (left, right) in pairs {
+        if left.same_definition(&right) {
+            identical_count += 1;
+        } else {
+            entries.push(CompareEntry {
+                kind: DiffKind::Changed,
+                left: Some(left),
+                right: Some(right),
+            });
+        }
+    }
+
+    entries.extend(right.into_iter().flatten().map(|stub| CompareEntry {
+        kind: DiffKind::OnlyRight,
+        left: None,
+        right: Some(stub),
+    }));
+    (entries, identical_count)
+}
+
+/// Line diff of the pretty printed JSON of both sides, aligned for display
+/// in two columns.
+pub fn diff_rows(entry: &CompareEntry) -> Vec<DiffRow> {
+    let to_json = |stub: &Option<StubMapping>| {
+        stub.as_ref()
+            .map(|s| serde_json::to_string_pretty(s).unwrap_or_else(|_| format!("{:#?}", s)))
+            .unwrap_or_default()
+    };
+    let left = to_json(&entry.left);
+    let right = to_json(&entry.right);
+    TextDiff::from_lines(&left, &right)
+        .iter_all_changes()
+        .map(|change| {
+            let line = change.value().trim_end_matches('\n').to_string();
+            match change.tag() {
+                ChangeTag::Equal => DiffRow {
+                    left: Some(line.clone()),
+                    right: Some(line),
+                    tag: ChangeTag::Equal,
+                },
+                ChangeTag::Delete => DiffRow {
+                    left: Some(line),
+                    right: None,
+                    tag: ChangeTag::Delete,
+                },
+                ChangeTag::Insert => DiffRow {
+                    left: None,
+                    right: Some(line),
+                    tag: ChangeTag::Insert,
+                },
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+pub enum CompareCommand {
+    LoadAndCompare,
+    SyncSelected(SyncDirection),
+}
+
+#[derive(Clone, Debug)]
+pub enum CompareMsg {
+    CycleLeftServer,
+    CycleRightServer,
+    SelectNext,
+    SelectPrevious,
+    ScrollDiffUp,
+    ScrollDiffDown,
+    CompareRequested,
+    SyncSelectedRequested(SyncDirection),
+    Confirm,
+    Back,
+}
+
+#[derive(Error, Debug)]
+pub enum CompareError {
+    #[error("Select two servers to compare")]
+    NoServerSelected,
+    #[error("Left and right server are the same, press 1 or 2 to pick another")]
+    SameServer,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn stub(id: &str, path: &str, status: u16) -> StubMapping {
+        serde_json::from_value(json!({
+            "id": id,
+            "request": {"method": "GET", "urlPath": path},
+            "response": {"status": status}
+        }))
+        .unwrap()
+    }
+
+    fn summary(entries: &[CompareEntry]) -> Vec<(DiffKind, Option<&str>, Option<&str>)> {
+        entries
+            .iter()
+            .map(|e| {
+                (
+                    e.kind,
+                    e.left.as_ref().map(|s| s.id.as_str()),
+                    e.right.as_ref().map(|s| s.id.as_str()),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pairs_stubs_by_id_then_by_request() {
+        let left = vec![
+            stub("same", "/same", 200),
+            stub("changed", "/changed", 200),
+            stub("left-id", "/moved", 200),
+            stub("only-left", "/left", 200),
+        ];
+        let right = vec![
+            stub("only-right", "/right", 200),
+            stub("right-id", "/moved", 200),
+            stub("changed", "/changed", 500),
+            stub("same", "/same", 200),
+        ];
+        let (entries, identical) = compare_stubs(left, right);
+        assert_eq!(identical, 2);
+        assert_eq!(
+            summary(&entries),
+            [
+                (DiffKind::OnlyLeft, Some("only-left"), None),
+                (DiffKind::Changed, Some("changed"), Some("changed")),
+                (DiffKind::OnlyRight, None, Some("only-right")),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_rows_line_up_both_sides() {
+        let entry = CompareEntry {
+            kind: DiffKind::Changed,
+            left: Some(stub("a", "/a", 200)),
+            right: Some(stub("a", "/a", 500)),
+        };
+        let rows = diff_rows(&entry);
+        let changed: Vec<(Option<&str>, Option<&str>)> = rows
+            .iter()
+            .filter(|r| r.tag != ChangeTag::Equal)
+            .map(|r| (r.left.as_deref(), r.right.as_deref()))
+            .collect();
+        assert_eq!(
+            changed,
+            [
+                (Some("    \"status\": 200"), None),
+                (None, Some("    \"status\": 500")),
+            ]
+        );
+        let left: Vec<&str> = rows.iter().filter_map(|r| r.left.as_deref()).collect();
+        let json: Value = serde_json::from_str(&left.join("\n")).unwrap();
+        assert_eq!(json["response"]["status"], 200);
+    }
+}
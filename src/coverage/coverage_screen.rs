@@ -0,0 +1,140 @@
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::stub::model::StubMsg;
+use crate::ui;
+use crate::wire_mock::client::StubMapping;
+use async_trait::async_trait;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct CoverageScreen {
+    sender: Sender<Message>,
+}
+
+impl CoverageScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        CoverageScreen { sender }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for CoverageScreen {
+    fn scope(&self) -> Scope {
+        Scope::Coverage
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.stub_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock Inspector - Stub Coverage", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_layout[1]);
+
+        let describe = |stub: &StubMapping| {
+            format!(
+                "{} {}",
+                stub.request.method,
+                stub.request.display_url().unwrap_or("(any url)")
+            )
+        };
+
+        // Stubs never hit (left side)
+        let never_hit: Vec<ListItem> = model
+            .stubs
+            .iter()
+            .filter(|stub| model.hit_count(stub) == 0)
+            .map(|stub| ListItem::new(describe(stub)).style(app.theme.error()))
+            .collect();
+        let never_hit_count = never_hit.len();
+        let never_hit = List::new(never_hit).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Never hit ({})", never_hit_count)),
+        );
+        frame.render_widget(never_hit, content_layout[0]);
+
+        // Busiest stubs (right side)
+        let mut hit: Vec<_> = model
+            .stubs
+            .iter()
+            .filter_map(|stub| model.hits.get(&stub.id).map(|hits| (stub, hits)))
+            .collect();
+        hit.sort_by_key(|(_, hits)| std::cmp::Reverse(hits.count));
+        let most_hit: Vec<ListItem> = hit
+            .iter()
+            .map(|(stub, hits)| {
+                ListItem::new(format!(
+                    "{:>6}× {}  (last {})",
+                    hits.count,
+                    describe(stub),
+                    hits.last_hit.as_deref().unwrap_or("unknown")
+                ))
+                .style(app.theme.success())
+            })
+            .collect();
+        let most_hit =
+            List::new(most_hit).block(Block::default().borders(Borders::ALL).title("Most hit"));
+        frame.render_widget(most_hit, content_layout[1]);
+
+        // Status
+        let total = model.stubs.len();
+        let covered = total - never_hit_count;
+        let percent = (covered * 100).checked_div(total).unwrap_or(0);
+        let journal = if model.journal_total > model.journal_size {
+            format!(
+                "the newest {} of {} journal requests, older hits are not counted",
+                model.journal_size, model.journal_total
+            )
+        } else {
+            format!("{} journal requests", model.journal_size)
+        };
+        let status = Paragraph::new(format!(
+            "{} of {} stubs hit ({}%) across {}",
+            covered, total, percent, journal
+        ));
+        frame.render_widget(status, main_layout[2]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Coverage, &[]);
+        ui::widgets::render_commands(frame, main_layout[3], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::Refresh => {
+                self.sender
+                    .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+                self.sender
+                    .send(Message::Stub(StubMsg::ReadHitsRequested))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
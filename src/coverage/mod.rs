@@ -0,0 +1 @@
+pub mod coverage_screen;
@@ -0,0 +1,145 @@
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::ui;
+use async_trait::async_trait;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::Modifier;
+use ratatui::widgets::{BarChart, Block, Borders, Gauge, List, ListItem, Paragraph, Sparkline};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct DashboardScreen {
+    sender: Sender<Message>,
+}
+
+impl DashboardScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        DashboardScreen { sender }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for DashboardScreen {
+    fn scope(&self) -> Scope {
+        Scope::Dashboard
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.dashboard_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(8),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock Inspector - Live Traffic", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        // Request rate, newest sample on the right
+        let rate_area = main_layout[1];
+        let visible = (rate_area.width.saturating_sub(2) as usize).min(model.rates.len());
+        let rates: Vec<u64> = model
+            .rates
+            .iter()
+            .skip(model.rates.len() - visible)
+            .copied()
+            .collect();
+        let peak = rates.iter().max().copied().unwrap_or(0);
+        let sparkline = Sparkline::default()
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "Requests/s: {} (peak {})",
+                model.current_rate(),
+                peak
+            )))
+            .data(&rates)
+            .style(app.theme.accent());
+        frame.render_widget(sparkline, rate_area);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_layout[2]);
+
+        // Status codes (left side)
+        let labels: Vec<String> = model.status_codes.keys().map(|s| s.to_string()).collect();
+        let bars: Vec<(&str, u64)> = labels
+            .iter()
+            .map(String::as_str)
+            .zip(model.status_codes.values().copied())
+            .collect();
+        let status_codes = BarChart::default()
+            .block(Block::default().borders(Borders::ALL).title("Status codes"))
+            .data(&bars)
+            .bar_width(5)
+            .bar_gap(1)
+            .bar_style(app.theme.highlight())
+            .value_style(app.theme.highlight().add_modifier(Modifier::REVERSED));
+        frame.render_widget(status_codes, content_layout[0]);
+
+        // Matched ratio and top endpoints (right side)
+        let right_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(3), Constraint::Min(0)])
+            .split(content_layout[1]);
+        let total = model.matched + model.unmatched;
+        let ratio = if total == 0 {
+            1.0
+        } else {
+            model.matched as f64 / total as f64
+        };
+        let matched = Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title("Matched"))
+            .gauge_style(app.theme.success().bg(app.theme.error))
+            .ratio(ratio)
+            .label(format!(
+                "{} matched / {} unmatched",
+                model.matched, model.unmatched
+            ));
+        frame.render_widget(matched, right_layout[0]);
+
+        let endpoints: Vec<ListItem> = model
+            .top_endpoints
+            .iter()
+            .map(|(endpoint, count)| ListItem::new(format!("{:>6}  {}", count, endpoint)))
+            .collect();
+        let endpoints = List::new(endpoints).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Top endpoints"),
+        );
+        frame.render_widget(endpoints, right_layout[1]);
+
+        // Status
+        let status =
+            Paragraph::new(model.status_message.clone().unwrap_or_else(|| {
+                format!("{} requests in the journal, polling every second", total)
+            }));
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Dashboard, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
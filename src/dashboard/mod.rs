@@ -0,0 +1,2 @@
+pub mod dashboard_screen;
+pub mod model;
@@ -0,0 +1,211 @@
+use crate::model::{Message, ModelTrait};
+use crate::wire_mock;
+use crate::wire_mock::client::ServeEvent;
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+use tokio::time::interval;
+
+/// Number of one-second samples kept for the request-rate sparkline.
+const RATE_HISTORY: usize = 120;
+
+/// Number of endpoints listed in the top endpoints panel.
+const TOP_ENDPOINTS: usize = 10;
+
+/// Newest journal entries read per poll; the statistics cover these only.
+const JOURNAL_LIMIT: usize = 1000;
+
+/// A poll gives up after this long, so a dead server never piles them up.
+const POLL_TIMEOUT: Duration = Duration::from_secs(2);
+
+pub struct DashboardModel {
+    pub selected_server_url: Option<String>,
+    pub event_sender: Sender<Message>,
+    pub poll_task: Option<tokio::task::JoinHandle<()>>,
+    /// Whether a poll is waiting for the server, so polls never overlap.
+    pub polling: bool,
+    /// Requests per second, oldest first.
+    pub rates: VecDeque<u64>,
+    pub matched: usize,
+    pub unmatched: usize,
+    pub status_codes: BTreeMap<u16, u64>,
+    pub top_endpoints: Vec<(String, usize)>,
+    pub status_message: Option<String>,
+    seen_ids: HashSet<String>,
+    last_poll: Option<Instant>,
+}
+
+#[async_trait]
+impl ModelTrait<DashboardMsg, DashboardCommand> for DashboardModel {
+    async fn apply_event(&mut self, event: DashboardMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            DashboardMsg::StartPolling => {
+                self.start_polling();
+                Ok(())
+            }
+            DashboardMsg::PollRequested => {
+                if let Err(err) = self.poll() {
+                    self.status_message = Some(err.to_string());
+                }
+                Ok(())
+            }
+            DashboardMsg::Polled(server_url, events) => {
+                self.polling = false;
+                // A poll that was under way when the server changed
+                if self.selected_server_url.as_ref() != Some(&server_url) {
+                    return Ok(());
+                }
+                match events {
+                    Ok(events) => self.update_statistics(&events),
+                    Err(err) => self.status_message = Some(err),
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, _: DashboardCommand) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
+
+impl DashboardModel {
+    pub fn new(event_sender: Sender<Message>) -> Self {
+        Self {
+            selected_server_url: None,
+            event_sender,
+            poll_task: None,
+            polling: false,
+            rates: VecDeque::new(),
+            matched: 0,
+            unmatched: 0,
+            status_codes: BTreeMap::new(),
+            top_endpoints: vec![],
+            status_message: None,
+            seen_ids: HashSet::new(),
+            last_poll: None,
+        }
+    }
+
+    /// Clears the statistics of a previous session on another server.
+    pub fn reset(&mut self, server_url: Option<String>) {
+        if self.selected_server_url != server_url {
+            self.stop_polling();
+            *self = Self::new(self.event_sender.clone());
+            self.selected_server_url = server_url;
+        }
+    }
+
+    pub fn current_rate(&self) -> u64 {
+        self.rates.back().copied().unwrap_or(0)
+    }
+
+    /// Called whenever the dashboard stops being shown, however it was left.
+    pub fn stop_polling(&mut self) {
+        if let Some(task) = self.poll_task.take() {
+            task.abort();
+        }
+    }
+
+    fn start_polling(&mut self) {
+        if self.poll_task.is_some() {
+            return;
+        }
+        let sender = self.event_sender.clone();
+        let task = tokio::spawn(async move {
+            let mut interval = interval(Duration::from_secs(1));
+            loop {
+                interval.tick().await;
+                if sender
+                    .send(Message::Dashboard(DashboardMsg::PollRequested))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+        self.poll_task = Some(task);
+    }
+
+    /// Reads the journal in the background.
+    fn poll(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .clone()
+            .ok_or(DashboardError::NoServerSelected)?;
+        if self.polling {
+            return Ok(());
+        }
+        self.polling = true;
+        let sender = self.event_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let events =
+                wire_mock::client::get_recent_requests(&server_url, JOURNAL_LIMIT, POLL_TIMEOUT)
+                    .map(|events| events.requests)
+                    .map_err(|err| err.to_string());
+            let _ = sender.send(Message::Dashboard(DashboardMsg::Polled(server_url, events)));
+        });
+        Ok(())
+    }
+
+    fn update_statistics(&mut self, events: &[ServeEvent]) {
+        // The journal may be capped, so count unseen ids rather than its length
+        let ids: HashSet<String> = events.iter().map(|e| e.id.clone()).collect();
+        let now = Instant::now();
+        if let Some(last_poll) = self.last_poll {
+            let new_requests = ids.difference(&self.seen_ids).count() as f64;
+            let seconds = now.duration_since(last_poll).as_secs_f64().max(1.0);
+            self.rates
+                .push_back((new_requests / seconds).round() as u64);
+            if self.rates.len() > RATE_HISTORY {
+                self.rates.pop_front();
+            }
+        }
+        self.seen_ids = ids;
+        self.last_poll = Some(now);
+
+        self.matched = events.iter().filter(|e| e.was_matched).count();
+        self.unmatched = events.len() - self.matched;
+        self.status_codes.clear();
+        let mut endpoints: HashMap<String, usize> = HashMap::new();
+        for event in events {
+            if let Some(response) = &event.response {
+                *self.status_codes.entry(response.status).or_default() += 1;
+            }
+            let path = event
+                .request
+                .url
+                .split_once('?')
+                .map_or(event.request.url.as_str(), |(path, _)| path);
+            *endpoints
+                .entry(format!("{} {}", event.request.method, path))
+                .or_default() += 1;
+        }
+        let mut endpoints: Vec<(String, usize)> = endpoints.into_iter().collect();
+        endpoints.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        endpoints.truncate(TOP_ENDPOINTS);
+        self.top_endpoints = endpoints;
+        self.status_message = None;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum DashboardCommand {}
+
+#[derive(Clone, Debug)]
+pub enum DashboardMsg {
+    StartPolling,
+    PollRequested,
+    /// The newest journal entries of the server, or why they could not be
+    /// read.
+    Polled(String, Result<Vec<ServeEvent>, String>),
+}
+
+#[derive(Error, Debug)]
+pub enum DashboardError {
+    #[error("No server selected")]
+    NoServerSelected,
+}
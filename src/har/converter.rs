@@ -0,0 +1,367 @@
+use crate::wire_mock::client::{RequestPattern, ResponseDefinition, StubMapping};
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+/// Response headers that describe the recorded transfer rather than the content.
+const SKIPPED_RESPONSE_HEADERS: [&str; 6] = [
+    "content-length",
+    "content-encoding",
+    "transfer-encoding",
+    "connection",
+    "date",
+    "keep-alive",
+];
+
+#[derive(Deserialize, Debug)]
+struct Har {
+    log: HarLog,
+}
+
+#[derive(Deserialize, Debug)]
+struct HarLog {
+    entries: Vec<HarEntry>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HarEntry {
+    pub request: HarRequest,
+    pub response: HarResponse,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HarRequest {
+    pub method: String,
+    pub url: String,
+    #[serde(default)]
+    pub query_string: Vec<HarNameValue>,
+    pub post_data: Option<HarPostData>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HarResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Vec<HarNameValue>,
+    pub content: Option<HarContent>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct HarNameValue {
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HarPostData {
+    #[serde(default)]
+    pub mime_type: String,
+    pub text: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HarContent {
+    #[serde(default)]
+    pub mime_type: String,
+    pub text: Option<String>,
+    pub encoding: Option<String>,
+}
+
+/// Which stub is kept when several entries produce the same request pattern.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DedupeStrategy {
+    First,
+    Last,
+    All,
+}
+
+#[derive(Clone, Debug)]
+pub struct HarOptions {
+    pub match_query: bool,
+    pub match_body: bool,
+    pub dedupe: DedupeStrategy,
+    pub host_filter: String,
+    pub content_type_filter: String,
+}
+
+pub fn load_har(path: &Path) -> Result<Vec<HarEntry>, Box<dyn Error>> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let har: Har = serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid HAR file {}: {}", path.display(), e))?;
+    Ok(har.log.entries)
+}
+
+/// Whether the browser recorded a response. Aborted, blocked and cached
+/// requests are logged with status 0, which no stub can return.
+pub fn has_response(entry: &HarEntry) -> bool {
+    entry.response.status != 0
+}
+
+/// Filters, converts and dedupes HAR entries into stub mappings. Entries
+/// without a response are left out.
+pub fn convert_entries(entries: &[HarEntry], options: &HarOptions) -> Vec<StubMapping> {
+    let mut mappings: Vec<StubMapping> = vec![];
+    for entry in entries
+        .iter()
+        .filter(|e| has_response(e) && matches_filters(e, options))
+    {
+        let mapping = convert_entry(entry, options);
+        let duplicate = mappings.iter().position(|m| m.request == mapping.request);
+        match (duplicate, options.dedupe) {
+            (Some(_), DedupeStrategy::First) => {}
+            (Some(i), DedupeStrategy::Last) => mappings[i] = mapping,
+            _ => mappings.push(mapping),
+        }
+    }
+    mappings
+}
+
+fn matches_filters(entry: &HarEntry, options: &HarOptions) -> bool {
+    let (host, _, _) = split_url(&entry.request.url);
+    let content_type = entry
+        .response
+        .content
+        .as_ref()
+        .map(|c| c.mime_type.as_str())
+        .unwrap_or("");
+    host.contains(options.host_filter.trim())
+        && content_type.contains(options.content_type_filter.trim())
+}
+
+fn convert_entry(entry: &HarEntry, options: &HarOptions) -> StubMapping {
+    let (_, path, _) = split_url(&entry.request.url);
+
+    let mut request_extra = Map::new();
+    if options.match_query && !entry.request.query_string.is_empty() {
+        let parameters: Map<String, Value> = entry
+            .request
+            .query_string
+            .iter()
+            .map(|q| (q.name.clone(), json!({ "equalTo": q.value })))
+            .collect();
+        request_extra.insert("queryParameters".to_string(), Value::Object(parameters));
+    }
+    if options.match_body
+        && let Some(post_data) = &entry.request.post_data
+        && let Some(text) = post_data.text.as_ref().filter(|t| !t.is_empty())
+    {
+        let pattern = if post_data.mime_type.contains("json") {
+            json!({ "equalToJson": text })
+        } else {
+            json!({ "equalTo": text })
+        };
+        request_extra.insert("bodyPatterns".to_string(), json!([pattern]));
+    }
+
+    let headers: HashMap<String, String> = entry
+        .response
+        .headers
+        .iter()
+        .filter(|h| !SKIPPED_RESPONSE_HEADERS.contains(&h.name.to_lowercase().as_str()))
+        .map(|h| (h.name.clone(), h.value.clone()))
+        .collect();
+
+    let mut response_extra = Map::new();
+    let mut body = None;
+    if let Some(content) = &entry.response.content
+        && let Some(text) = content.text.clone()
+    {
+        if content.encoding.as_deref() == Some("base64") {
+            response_extra.insert("base64Body".to_string(), Value::String(text));
+        } else {
+            body = Some(text);
+        }
+    }
+
+    StubMapping {
+        id: String::new(),
+        request: RequestPattern {
+            method: entry.request.method.to_uppercase(),
+            url: None,
+            url_pattern: None,
+            url_path: Some(path),
+            url_path_pattern: None,
+            extra: request_extra,
+        },
+        response: ResponseDefinition {
+            status: entry.response.status,
+            body,
+            headers: if headers.is_empty() {
+                None
+            } else {
+                Some(headers)
+            },
+            extra: response_extra,
+        },
+        priority: None,
+        scenario_name: None,
+        extra: Map::new(),
+    }
+}
+
+/// Splits an absolute url into host, path and query string.
+pub fn split_url(url: &str) -> (String, String, String) {
+    let without_scheme = url.find("://").map(|i| &url[i + 3..]).unwrap_or(url);
+    let (host, rest) = match without_scheme.find('/') {
+        Some(i) => (&without_scheme[..i], &without_scheme[i..]),
+        None => (without_scheme, "/"),
+    };
+    let rest = rest.split('#').next().unwrap_or(rest);
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    (host.to_string(), path.to_string(), query.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(url: &str, status: u16, body: &str) -> HarEntry {
+        let (_, _, query) = split_url(url);
+        let query_string: Vec<Value> = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(name, value)| json!({"name": name, "value": value}))
+            .collect();
+        serde_json::from_value(json!({
+            "request": {"method": "get", "url": url, "queryString": query_string},
+            "response": {
+                "status": status,
+                "headers": [
+                    {"name": "Content-Type", "value": "application/json"},
+                    {"name": "Content-Length", "value": "2"},
+                    {"name": "Date", "value": "Mon, 01 Jan 2024 00:00:00 GMT"},
+                    {"name": "X-Trace", "value": "abc"}
+                ],
+                "content": {"mimeType": "application/json", "text": body}
+            }
+        }))
+        .unwrap()
+    }
+
+    fn options(dedupe: DedupeStrategy) -> HarOptions {
+        HarOptions {
+            match_query: true,
+            match_body: false,
+            dedupe,
+            host_filter: String::new(),
+            content_type_filter: String::new(),
+        }
+    }
+
+    fn bodies(mappings: &[StubMapping]) -> Vec<&str> {
+        mappings
+            .iter()
+            .map(|m| m.response.body.as_deref().unwrap())
+            .collect()
+    }
+
+    fn recording() -> Vec<HarEntry> {
+        vec![
+            entry("https://api.example.com/a?q=1", 200, "first"),
+            entry("https://api.example.com/b", 200, "other"),
+            entry("https://api.example.com/a?q=1", 200, "second"),
+        ]
+    }
+
+    #[test]
+    fn dedupes_by_request_pattern() {
+        let entries = recording();
+        let first = convert_entries(&entries, &options(DedupeStrategy::First));
+        assert_eq!(bodies(&first), ["first", "other"]);
+        let last = convert_entries(&entries, &options(DedupeStrategy::Last));
+        assert_eq!(bodies(&last), ["second", "other"]);
+        let all = convert_entries(&entries, &options(DedupeStrategy::All));
+        assert_eq!(bodies(&all), ["first", "other", "second"]);
+    }
+
+    #[test]
+    fn a_different_query_is_no_duplicate_unless_the_query_is_ignored() {
+        let entries = vec![
+            entry("https://api.example.com/a?q=1", 200, "one"),
+            entry("https://api.example.com/a?q=2", 200, "two"),
+        ];
+        let with_query = convert_entries(&entries, &options(DedupeStrategy::First));
+        assert_eq!(bodies(&with_query), ["one", "two"]);
+        let without_query = convert_entries(
+            &entries,
+            &HarOptions {
+                match_query: false,
+                ..options(DedupeStrategy::First)
+            },
+        );
+        assert_eq!(bodies(&without_query), ["one"]);
+    }
+
+    #[test]
+    fn skips_entries_without_a_response() {
+        let entries = vec![
+            entry("https://api.example.com/aborted", 0, ""),
+            entry("https://api.example.com/ok", 200, "ok"),
+        ];
+        assert!(!has_response(&entries[0]));
+        let mappings = convert_entries(&entries, &options(DedupeStrategy::All));
+        assert_eq!(bodies(&mappings), ["ok"]);
+    }
+
+    #[test]
+    fn keeps_only_headers_describing_the_content() {
+        let entries = [entry("https://api.example.com/a", 200, "{}")];
+        let mappings = convert_entries(&entries, &options(DedupeStrategy::All));
+        let mut names: Vec<&String> = mappings[0]
+            .response
+            .headers
+            .as_ref()
+            .unwrap()
+            .keys()
+            .collect();
+        names.sort();
+        assert_eq!(names, ["Content-Type", "X-Trace"]);
+    }
+
+    #[test]
+    fn filters_by_host_and_content_type() {
+        let mut entries = recording();
+        entries.push(entry("https://cdn.example.com/logo", 200, "logo"));
+        let by_host = convert_entries(
+            &entries,
+            &HarOptions {
+                host_filter: " cdn. ".to_string(),
+                ..options(DedupeStrategy::All)
+            },
+        );
+        assert_eq!(bodies(&by_host), ["logo"]);
+        let by_content_type = convert_entries(
+            &entries,
+            &HarOptions {
+                content_type_filter: "image/".to_string(),
+                ..options(DedupeStrategy::All)
+            },
+        );
+        assert!(by_content_type.is_empty());
+    }
+
+    #[test]
+    fn splits_urls_into_host_path_and_query() {
+        assert_eq!(
+            split_url("https://api.example.com:8443/a/b?q=1#top"),
+            (
+                "api.example.com:8443".to_string(),
+                "/a/b".to_string(),
+                "q=1".to_string()
+            )
+        );
+        assert_eq!(
+            split_url("http://localhost"),
+            ("localhost".to_string(), "/".to_string(), String::new())
+        );
+    }
+}
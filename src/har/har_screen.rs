@@ -0,0 +1,216 @@
+use crate::har::converter::DedupeStrategy;
+use crate::har::model::{HarField, HarMsg};
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::ui;
+use async_trait::async_trait;
+use crossterm::event::{Event, KeyCode};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::Style;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct HarScreen {
+    sender: Sender<Message>,
+}
+
+impl HarScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        HarScreen { sender }
+    }
+
+    fn input<'a>(&self, app: &ApplicationModel, field: HarField, title: &'a str) -> Paragraph<'a> {
+        let model = &app.har_model;
+        let value = match field {
+            HarField::Path => &model.path_input,
+            HarField::HostFilter => &model.options.host_filter,
+            HarField::ContentTypeFilter => &model.options.content_type_filter,
+        };
+        let (text, style) = if model.focused_field == field {
+            (format!("{}█", value), app.theme.highlight())
+        } else {
+            (value.clone(), Style::default())
+        };
+        Paragraph::new(text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(style)
+                .title(title),
+        )
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for HarScreen {
+    fn scope(&self) -> Scope {
+        Scope::Har
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.har_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock - Import HAR File", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        // Inputs
+        let input_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Percentage(50),
+                Constraint::Percentage(25),
+                Constraint::Percentage(25),
+            ])
+            .split(main_layout[1]);
+        frame.render_widget(self.input(app, HarField::Path, "HAR file"), input_layout[0]);
+        frame.render_widget(
+            self.input(app, HarField::HostFilter, "Host filter"),
+            input_layout[1],
+        );
+        frame.render_widget(
+            self.input(app, HarField::ContentTypeFilter, "Content type filter"),
+            input_layout[2],
+        );
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(main_layout[2]);
+
+        // Preview (left side)
+        let items: Vec<ListItem> = model
+            .mappings
+            .iter()
+            .enumerate()
+            .map(|(i, mapping)| {
+                let url = mapping.request.display_url().unwrap_or("(no url)");
+                let (text, style) = if i == model.selected_mapping_index {
+                    (
+                        format!(
+                            "▶ {} {} → {}",
+                            mapping.request.method, url, mapping.response.status
+                        ),
+                        app.theme.selected(),
+                    )
+                } else {
+                    (
+                        format!(
+                            "  {} {} → {}",
+                            mapping.request.method, url, mapping.response.status
+                        ),
+                        app.theme.text(),
+                    )
+                };
+                ListItem::new(text).style(style)
+            })
+            .collect();
+        let dedupe = match model.options.dedupe {
+            DedupeStrategy::First => "first",
+            DedupeStrategy::Last => "last",
+            DedupeStrategy::All => "all",
+        };
+        let on_off = |flag: bool| if flag { "on" } else { "off" };
+        let preview =
+            List::new(items).block(Block::default().borders(Borders::ALL).title(format!(
+                "Preview - query: {}, body: {}, duplicates: keep {}",
+                on_off(model.options.match_query),
+                on_off(model.options.match_body),
+                dedupe
+            )));
+        frame.render_widget(preview, content_layout[0]);
+
+        // Details (right side)
+        let details = match model.selected_mapping() {
+            Some(mapping) => {
+                serde_json::to_string_pretty(mapping).unwrap_or_else(|_| format!("{:#?}", mapping))
+            }
+            None => "Enter a HAR file and press Enter".to_string(),
+        };
+        let details = Paragraph::new(details)
+            .block(Block::default().borders(Borders::ALL).title("Details"))
+            .style(app.theme.text())
+            .wrap(Wrap { trim: false });
+        frame.render_widget(details, content_layout[1]);
+
+        // Status
+        let status = Paragraph::new(model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Har, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::MatchQuery => {
+                self.sender.send(Message::Har(HarMsg::ToggleMatchQuery))?;
+                Ok(())
+            }
+            Action::MatchBody => {
+                self.sender.send(Message::Har(HarMsg::ToggleMatchBody))?;
+                Ok(())
+            }
+            Action::Duplicates => {
+                self.sender
+                    .send(Message::Har(HarMsg::CycleDedupeStrategy))?;
+                Ok(())
+            }
+            Action::Push => {
+                self.sender.send(Message::Har(HarMsg::PushRequested))?;
+                Ok(())
+            }
+            Action::NextField => {
+                self.sender.send(Message::Har(HarMsg::FocusNextField))?;
+                Ok(())
+            }
+            Action::Load => {
+                self.sender.send(Message::Har(HarMsg::LoadRequested))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender.send(Message::Har(HarMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Har(HarMsg::SelectNext))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(c) => {
+                    self.sender.send(Message::Har(HarMsg::InputChar(c)))?;
+                    Ok(())
+                }
+                KeyCode::Backspace => {
+                    self.sender.send(Message::Har(HarMsg::InputBackspace))?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod converter;
+pub mod har_screen;
+pub mod model;
@@ -0,0 +1,237 @@
+use crate::har::converter;
+use crate::har::converter::{DedupeStrategy, HarEntry, HarOptions};
+use crate::model::{Command, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::stub::model::StubMsg;
+use crate::wire_mock;
+use crate::wire_mock::client::{DuplicatePolicy, StubMapping};
+use async_trait::async_trait;
+use std::error::Error;
+use std::path::Path;
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HarField {
+    Path,
+    HostFilter,
+    ContentTypeFilter,
+}
+
+pub struct HarModel {
+    pub selected_server_url: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub path_input: String,
+    pub focused_field: HarField,
+    pub options: HarOptions,
+    pub entries: Vec<HarEntry>,
+    pub mappings: Vec<StubMapping>,
+    pub selected_mapping_index: usize,
+    pub status_message: Option<String>,
+}
+
+#[async_trait]
+impl ModelTrait<HarMsg, HarCommand> for HarModel {
+    async fn apply_event(&mut self, event: HarMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            HarMsg::InputChar(c) => {
+                self.focused_input().push(c);
+                self.convert();
+                Ok(())
+            }
+            HarMsg::InputBackspace => {
+                self.focused_input().pop();
+                self.convert();
+                Ok(())
+            }
+            HarMsg::FocusNextField => {
+                self.focused_field = match self.focused_field {
+                    HarField::Path => HarField::HostFilter,
+                    HarField::HostFilter => HarField::ContentTypeFilter,
+                    HarField::ContentTypeFilter => HarField::Path,
+                };
+                Ok(())
+            }
+            HarMsg::ToggleMatchQuery => {
+                self.options.match_query = !self.options.match_query;
+                self.convert();
+                Ok(())
+            }
+            HarMsg::ToggleMatchBody => {
+                self.options.match_body = !self.options.match_body;
+                self.convert();
+                Ok(())
+            }
+            HarMsg::CycleDedupeStrategy => {
+                self.options.dedupe = match self.options.dedupe {
+                    DedupeStrategy::First => DedupeStrategy::Last,
+                    DedupeStrategy::Last => DedupeStrategy::All,
+                    DedupeStrategy::All => DedupeStrategy::First,
+                };
+                self.convert();
+                Ok(())
+            }
+            HarMsg::SelectNext => {
+                if !self.mappings.is_empty() {
+                    self.selected_mapping_index =
+                        (self.selected_mapping_index + 1).min(self.mappings.len() - 1);
+                }
+                Ok(())
+            }
+            HarMsg::SelectPrevious => {
+                self.selected_mapping_index = self.selected_mapping_index.saturating_sub(1);
+                Ok(())
+            }
+            HarMsg::LoadRequested => {
+                self.command_sender
+                    .send(Command::Har(HarCommand::LoadHar))?;
+                Ok(())
+            }
+            HarMsg::PushRequested => {
+                self.command_sender
+                    .send(Command::Har(HarCommand::PushStubs))?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: HarCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            HarCommand::LoadHar => self.load_har(),
+            HarCommand::PushStubs => self.push_stubs(),
+        };
+        if let Err(err) = result {
+            self.status_message = Some(err.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl HarModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            selected_server_url: None,
+            read_only_servers,
+            event_sender,
+            command_sender,
+            path_input: String::new(),
+            focused_field: HarField::Path,
+            options: HarOptions {
+                match_query: false,
+                match_body: false,
+                dedupe: DedupeStrategy::First,
+                host_filter: String::new(),
+                content_type_filter: String::new(),
+            },
+            entries: vec![],
+            mappings: vec![],
+            selected_mapping_index: 0,
+            status_message: None,
+        }
+    }
+
+    pub fn selected_mapping(&self) -> Option<&StubMapping> {
+        self.mappings.get(self.selected_mapping_index)
+    }
+
+    fn focused_input(&mut self) -> &mut String {
+        match self.focused_field {
+            HarField::Path => &mut self.path_input,
+            HarField::HostFilter => &mut self.options.host_filter,
+            HarField::ContentTypeFilter => &mut self.options.content_type_filter,
+        }
+    }
+
+    fn convert(&mut self) {
+        self.mappings = converter::convert_entries(&self.entries, &self.options);
+        self.selected_mapping_index = self
+            .selected_mapping_index
+            .min(self.mappings.len().saturating_sub(1));
+    }
+
+    fn load_har(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.path_input.trim().is_empty() {
+            return Err(Box::new(HarError::NoPathGiven));
+        }
+        self.entries = converter::load_har(Path::new(self.path_input.trim()))?;
+        self.selected_mapping_index = 0;
+        self.convert();
+        let without_response = self
+            .entries
+            .iter()
+            .filter(|e| !converter::has_response(e))
+            .count();
+        let mut status = format!("{} entries loaded", self.entries.len());
+        if without_response > 0 {
+            status.push_str(&format!(
+                ", {} without a response skipped",
+                without_response
+            ));
+        }
+        status.push_str(&format!(", {} stubs after filters", self.mappings.len()));
+        self.status_message = Some(status);
+        Ok(())
+    }
+
+    fn push_stubs(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .as_ref()
+            .ok_or(HarError::NoServerSelected)?;
+        self.read_only_servers
+            .check(server_url, "importing HAR recordings")?;
+        if self.mappings.is_empty() {
+            return Err(Box::new(HarError::NothingToPush));
+        }
+        // The mappings have no ids, so the import adds every one as a new
+        // stub; they are dropped so a second push cannot add them again.
+        wire_mock::client::import_stubs(server_url, &self.mappings, DuplicatePolicy::Overwrite)?;
+        self.status_message = Some(format!(
+            "Added {} new stubs to {}, load the HAR file again to push more",
+            self.mappings.len(),
+            server_url
+        ));
+        self.entries.clear();
+        self.mappings.clear();
+        self.selected_mapping_index = 0;
+        self.event_sender
+            .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum HarCommand {
+    LoadHar,
+    PushStubs,
+}
+
+#[derive(Clone, Debug)]
+pub enum HarMsg {
+    InputChar(char),
+    InputBackspace,
+    FocusNextField,
+    ToggleMatchQuery,
+    ToggleMatchBody,
+    CycleDedupeStrategy,
+    SelectNext,
+    SelectPrevious,
+    LoadRequested,
+    PushRequested,
+}
+
+#[derive(Error, Debug)]
+pub enum HarError {
+    #[error("No server selected")]
+    NoServerSelected,
+    #[error("Enter the path of a HAR file first")]
+    NoPathGiven,
+    #[error("No stubs to push, load a HAR file or relax the filters")]
+    NothingToPush,
+}
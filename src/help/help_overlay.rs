@@ -0,0 +1,103 @@
+use crate::help::model::HelpMsg;
+use crate::keymap::action::{Action, Scope};
+use crate::keymap::Binding;
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::ui;
+use async_trait::async_trait;
+use ratatui::prelude::{Line, Modifier, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+/// Headings of the help overlay, in display order.
+const GROUPS: [&str; 4] = ["General", "Navigation", "Actions", "Screens"];
+
+/// Modal listing every key of the screen underneath, grouped, with what the
+/// keys do.
+pub struct HelpOverlay {
+    sender: Sender<Message>,
+}
+
+impl HelpOverlay {
+    pub fn new(sender: Sender<Message>) -> Self {
+        HelpOverlay { sender }
+    }
+
+    fn help_lines(&self, app: &ApplicationModel) -> Vec<Line<'static>> {
+        let scope = app.help_model.scope;
+        let bindings = app.keymap.bindings(scope);
+        let keys_width = bindings
+            .iter()
+            .map(|b| b.keys_text().chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut lines = vec![];
+        for group in GROUPS {
+            let entries: Vec<&Binding> = bindings
+                .iter()
+                .filter(|b| !b.keys.is_empty() && b.action.group(scope) == group)
+                .collect();
+            if entries.is_empty() {
+                continue;
+            }
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+            lines.push(Line::styled(
+                group,
+                app.theme.accent().add_modifier(Modifier::BOLD),
+            ));
+            for binding in entries {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("  {:<width$}  ", binding.keys_text(), width = keys_width),
+                        app.theme.highlight(),
+                    ),
+                    Span::raw(binding.description),
+                ]));
+            }
+        }
+        lines
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for HelpOverlay {
+    fn scope(&self) -> Scope {
+        Scope::Help
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let lines = self.help_lines(app);
+        let height = (lines.len() as u16 + 2).min(frame.area().height.saturating_sub(2));
+        let area = ui::widgets::centered_rect(70, height, frame.area());
+        let help = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Keys (Esc to close)"),
+            )
+            .scroll((app.help_model.scroll_offset as u16, 0));
+        frame.render_widget(Clear, area);
+        frame.render_widget(help, area);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back | Action::Help => {
+                self.sender.send(Message::Global(GlobalMsg::CloseOverlay))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender.send(Message::Help(HelpMsg::ScrollUp))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Help(HelpMsg::ScrollDown))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+pub mod help_overlay;
+pub mod model;
@@ -0,0 +1,53 @@
+use crate::keymap::action::Scope;
+use crate::model::ModelTrait;
+use async_trait::async_trait;
+use std::error::Error;
+
+/// Which screen's keys the help overlay lists.
+pub struct HelpModel {
+    pub scope: Scope,
+    pub scroll_offset: usize,
+}
+
+#[async_trait]
+impl ModelTrait<HelpMsg, HelpCommand> for HelpModel {
+    async fn apply_event(&mut self, event: HelpMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            HelpMsg::ScrollUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                Ok(())
+            }
+            HelpMsg::ScrollDown => {
+                self.scroll_offset += 1;
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, _: HelpCommand) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
+
+impl HelpModel {
+    pub fn new() -> Self {
+        Self {
+            scope: Scope::ServerSelection,
+            scroll_offset: 0,
+        }
+    }
+
+    pub fn reset(&mut self, scope: Scope) {
+        self.scope = scope;
+        self.scroll_offset = 0;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum HelpCommand {}
+
+#[derive(Clone, Debug)]
+pub enum HelpMsg {
+    ScrollUp,
+    ScrollDown,
+}
@@ -0,0 +1,56 @@
+use crate::wire_mock::client::{StubMapping, StubMappings};
+use serde_json::Value;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Reads stub mappings from a single JSON file or, recursively, from every
+/// `*.json` file below a directory. A file may hold one mapping or a
+/// `{"mappings": [...]}` document.
+pub fn load_mappings(path: &Path) -> Result<Vec<(PathBuf, StubMapping)>, Box<dyn Error>> {
+    let mut files = vec![];
+    if path.is_dir() {
+        collect_json_files(path, &mut files)?;
+    } else {
+        files.push(path.to_path_buf());
+    }
+
+    let mut mappings = vec![];
+    for file in files {
+        for mapping in read_mapping_file(&file)? {
+            mappings.push((file.clone(), mapping));
+        }
+    }
+    Ok(mappings)
+}
+
+pub fn read_mapping_file(file: &Path) -> Result<Vec<StubMapping>, Box<dyn Error>> {
+    let content = fs::read_to_string(file)
+        .map_err(|e| format!("Failed to read {}: {}", file.display(), e))?;
+    let document: Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid JSON in {}: {}", file.display(), e))?;
+    let mappings = if document.get("mappings").is_some() {
+        serde_json::from_value::<StubMappings>(document)
+            .map_err(|e| format!("Invalid mappings in {}: {}", file.display(), e))?
+            .mappings
+    } else {
+        vec![serde_json::from_value::<StubMapping>(document)
+            .map_err(|e| format!("Invalid mapping in {}: {}", file.display(), e))?]
+    };
+    Ok(mappings)
+}
+
+pub fn collect_json_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    for entry in entries {
+        if entry.is_dir() {
+            collect_json_files(&entry, files)?;
+        } else if entry.extension().is_some_and(|ext| ext == "json") {
+            files.push(entry);
+        }
+    }
+    Ok(())
+}
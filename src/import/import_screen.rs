@@ -0,0 +1,173 @@
+use crate::import::model::{ImportMsg, ImportStatus};
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::ui;
+use async_trait::async_trait;
+use crossterm::event::{Event, KeyCode};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct ImportScreen {
+    sender: Sender<Message>,
+}
+
+impl ImportScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        ImportScreen { sender }
+    }
+
+    fn get_entry_details(&self, app: &ApplicationModel) -> String {
+        match app.import_model.selected_entry() {
+            None => "Enter a mapping file or directory and press Enter".to_string(),
+            Some(entry) => {
+                let json = serde_json::to_string_pretty(&entry.mapping)
+                    .unwrap_or_else(|_| format!("{:#?}", entry.mapping));
+                format!("Source: {}\n\n{}", entry.source.display(), json)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for ImportScreen {
+    fn scope(&self) -> Scope {
+        Scope::Import
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.import_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock - Import Stubs", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        // Path input
+        let input = Paragraph::new(format!("{}█", model.path_input)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Mapping file or directory"),
+        );
+        frame.render_widget(input, main_layout[1]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(main_layout[2]);
+
+        // Preview list (left side)
+        let items: Vec<ListItem> = model
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let (badge, color) = match entry.status {
+                    ImportStatus::New => ("NEW", app.theme.success),
+                    ImportStatus::Changed => ("CHG", app.theme.highlight),
+                    ImportStatus::Identical => ("===", app.theme.muted),
+                };
+                let url = entry.mapping.request.display_url().unwrap_or("(no url)");
+                let marker = if i == model.selected_entry_index {
+                    "▶"
+                } else {
+                    " "
+                };
+                let mut style = Style::default().fg(color);
+                if i == model.selected_entry_index {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                ListItem::new(format!(
+                    "{} [{}] {} {}",
+                    marker, badge, entry.mapping.request.method, url
+                ))
+                .style(style)
+            })
+            .collect();
+
+        let preview_list = List::new(items).block(Block::default().borders(Borders::ALL).title(
+            format!("Preview - duplicates: {:?}", model.duplicate_policy),
+        ));
+        frame.render_widget(preview_list, content_layout[0]);
+
+        // Details view (right side)
+        let details = Paragraph::new(self.get_entry_details(app))
+            .block(Block::default().borders(Borders::ALL).title("Details"))
+            .style(app.theme.text())
+            .wrap(Wrap { trim: false });
+        frame.render_widget(details, content_layout[1]);
+
+        // Status
+        let status = Paragraph::new(model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Import, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::Import => {
+                self.sender
+                    .send(Message::Import(ImportMsg::ImportRequested))?;
+                Ok(())
+            }
+            Action::Preview => {
+                self.sender
+                    .send(Message::Import(ImportMsg::PreviewRequested))?;
+                Ok(())
+            }
+            Action::DuplicatePolicy => {
+                self.sender
+                    .send(Message::Import(ImportMsg::ToggleDuplicatePolicy))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::Import(ImportMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Import(ImportMsg::SelectNext))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(c) => {
+                    self.sender.send(Message::Import(ImportMsg::InputChar(c)))?;
+                    Ok(())
+                }
+                KeyCode::Backspace => {
+                    self.sender
+                        .send(Message::Import(ImportMsg::InputBackspace))?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
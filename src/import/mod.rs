@@ -0,0 +1,3 @@
+pub mod files;
+pub mod import_screen;
+pub mod model;
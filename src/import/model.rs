@@ -0,0 +1,231 @@
+use crate::import::files;
+use crate::model::{Command, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::stub::model::StubMsg;
+use crate::wire_mock;
+use crate::wire_mock::client::{DuplicatePolicy, StubMapping};
+use async_trait::async_trait;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ImportStatus {
+    New,
+    Changed,
+    Identical,
+}
+
+#[derive(Clone, Debug)]
+pub struct ImportEntry {
+    pub source: PathBuf,
+    pub mapping: StubMapping,
+    pub status: ImportStatus,
+}
+
+pub struct ImportModel {
+    pub selected_server_url: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub path_input: String,
+    pub entries: Vec<ImportEntry>,
+    pub selected_entry_index: usize,
+    pub duplicate_policy: DuplicatePolicy,
+    pub status_message: Option<String>,
+}
+
+#[async_trait]
+impl ModelTrait<ImportMsg, ImportCommand> for ImportModel {
+    async fn apply_event(&mut self, event: ImportMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            ImportMsg::InputChar(c) => {
+                self.path_input.push(c);
+                Ok(())
+            }
+            ImportMsg::InputBackspace => {
+                self.path_input.pop();
+                Ok(())
+            }
+            ImportMsg::SelectNext => {
+                if !self.entries.is_empty() {
+                    self.selected_entry_index =
+                        (self.selected_entry_index + 1).min(self.entries.len() - 1);
+                }
+                Ok(())
+            }
+            ImportMsg::SelectPrevious => {
+                self.selected_entry_index = self.selected_entry_index.saturating_sub(1);
+                Ok(())
+            }
+            ImportMsg::ToggleDuplicatePolicy => {
+                self.duplicate_policy = match self.duplicate_policy {
+                    DuplicatePolicy::Overwrite => DuplicatePolicy::Ignore,
+                    DuplicatePolicy::Ignore => DuplicatePolicy::Overwrite,
+                };
+                Ok(())
+            }
+            ImportMsg::PreviewRequested => {
+                self.command_sender
+                    .send(Command::Import(ImportCommand::LoadPreview))?;
+                Ok(())
+            }
+            ImportMsg::ImportRequested => {
+                self.command_sender
+                    .send(Command::Import(ImportCommand::ImportEntries))?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: ImportCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            ImportCommand::LoadPreview => self.load_preview(),
+            ImportCommand::ImportEntries => self.import_entries(),
+        };
+        if let Err(err) = result {
+            self.status_message = Some(err.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl ImportModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            selected_server_url: None,
+            read_only_servers,
+            event_sender,
+            command_sender,
+            path_input: String::new(),
+            entries: vec![],
+            selected_entry_index: 0,
+            duplicate_policy: DuplicatePolicy::Overwrite,
+            status_message: None,
+        }
+    }
+
+    pub fn selected_entry(&self) -> Option<&ImportEntry> {
+        self.entries.get(self.selected_entry_index)
+    }
+
+    fn load_preview(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .as_ref()
+            .ok_or(ImportError::NoServerSelected)?;
+        if self.path_input.trim().is_empty() {
+            return Err(Box::new(ImportError::NoPathGiven));
+        }
+        let mappings = files::load_mappings(Path::new(self.path_input.trim()))?;
+        let server_stubs = wire_mock::client::get_all_stubs(server_url)?.mappings;
+
+        self.entries = mappings
+            .into_iter()
+            .map(|(source, mut mapping)| {
+                let status = classify(&mut mapping, &server_stubs);
+                ImportEntry {
+                    source,
+                    mapping,
+                    status,
+                }
+            })
+            .collect();
+        self.selected_entry_index = 0;
+        self.status_message = Some(format!(
+            "{} new, {} changed, {} identical",
+            self.count(ImportStatus::New),
+            self.count(ImportStatus::Changed),
+            self.count(ImportStatus::Identical),
+        ));
+        Ok(())
+    }
+
+    fn import_entries(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .as_ref()
+            .ok_or(ImportError::NoServerSelected)?;
+        self.read_only_servers
+            .check(server_url, "importing stubs")?;
+        if self.entries.is_empty() {
+            return Err(Box::new(ImportError::NothingToImport));
+        }
+        let mappings: Vec<StubMapping> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.status != ImportStatus::Identical)
+            .map(|entry| entry.mapping.clone())
+            .collect();
+        if !mappings.is_empty() {
+            wire_mock::client::import_stubs(server_url, &mappings, self.duplicate_policy)?;
+        }
+        self.event_sender
+            .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+        let skipped = self.count(ImportStatus::Identical);
+        self.load_preview()?;
+        self.status_message = Some(format!(
+            "Imported {} stubs ({:?}), {} identical skipped",
+            mappings.len(),
+            self.duplicate_policy,
+            skipped
+        ));
+        Ok(())
+    }
+
+    fn count(&self, status: ImportStatus) -> usize {
+        self.entries.iter().filter(|e| e.status == status).count()
+    }
+}
+
+/// Compares `mapping` with the server's stubs. A mapping without an id takes
+/// the id of the server stub with the same request, as the import would
+/// otherwise add it as a new stub next to that one.
+fn classify(mapping: &mut StubMapping, server_stubs: &[StubMapping]) -> ImportStatus {
+    let existing = if mapping.id.is_empty() {
+        let existing = server_stubs.iter().find(|s| s.request == mapping.request);
+        if let Some(stub) = existing {
+            mapping.id = stub.id.clone();
+        }
+        existing
+    } else {
+        server_stubs.iter().find(|s| s.id == mapping.id)
+    };
+    match existing {
+        None => ImportStatus::New,
+        Some(stub) if stub.same_definition(mapping) => ImportStatus::Identical,
+        Some(_) => ImportStatus::Changed,
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ImportCommand {
+    LoadPreview,
+    ImportEntries,
+}
+
+#[derive(Clone, Debug)]
+pub enum ImportMsg {
+    InputChar(char),
+    InputBackspace,
+    SelectNext,
+    SelectPrevious,
+    ToggleDuplicatePolicy,
+    PreviewRequested,
+    ImportRequested,
+}
+
+#[derive(Error, Debug)]
+pub enum ImportError {
+    #[error("No server selected")]
+    NoServerSelected,
+    #[error("Enter a mapping file or directory first")]
+    NoPathGiven,
+    #[error("Nothing to import, load a preview first")]
+    NothingToImport,
+}
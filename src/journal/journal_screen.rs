@@ -0,0 +1,167 @@
+use crate::journal::model::JournalMsg;
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::ui;
+use async_trait::async_trait;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct JournalScreen {
+    sender: Sender<Message>,
+}
+
+impl JournalScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        JournalScreen { sender }
+    }
+
+    fn get_request_details(&self, app: &ApplicationModel) -> String {
+        match app.journal_model.selected_request() {
+            None => "No requests logged".to_string(),
+            Some(event) => {
+                serde_json::to_string_pretty(event).unwrap_or_else(|_| format!("{:#?}", event))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for JournalScreen {
+    fn scope(&self) -> Scope {
+        Scope::Journal
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.journal_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::server_title_paragraph(
+            "Wire Mock Inspector - Request Journal",
+            model.is_read_only(),
+            &app.theme,
+        );
+        frame.render_widget(title, main_layout[0]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(main_layout[1]);
+
+        // Requests (left side)
+        let items: Vec<ListItem> = model
+            .visible_requests()
+            .iter()
+            .enumerate()
+            .map(|(i, event)| {
+                let status = event
+                    .response
+                    .as_ref()
+                    .map(|r| r.status.to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                let matched = if event.was_matched { " " } else { "✗" };
+                let text = format!(
+                    "{} {} {} → {}",
+                    matched, event.request.method, event.request.url, status
+                );
+                let style = if i == model.selected_request_index {
+                    app.theme.selected()
+                } else if !event.was_matched {
+                    app.theme.error()
+                } else {
+                    app.theme.text()
+                };
+                ListItem::new(text).style(style)
+            })
+            .collect();
+        let list_title = if model.unmatched_only {
+            "Unmatched requests"
+        } else {
+            "Requests"
+        };
+        let requests =
+            List::new(items).block(Block::default().borders(Borders::ALL).title(list_title));
+        frame.render_widget(requests, content_layout[0]);
+
+        // Details (right side)
+        let details = Paragraph::new(self.get_request_details(app))
+            .block(Block::default().borders(Borders::ALL).title("Details"))
+            .style(app.theme.text())
+            .wrap(Wrap { trim: false })
+            .scroll((model.scroll_offset as u16, 0));
+        frame.render_widget(details, content_layout[1]);
+
+        // Status
+        let status = Paragraph::new(model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[2]);
+
+        // Stubbing a request is hidden when the server is read-only
+        let hidden: &[Action] = if model.is_read_only() {
+            &[Action::StubRequest]
+        } else {
+            &[]
+        };
+        let commands = app.keymap.commands(Scope::Journal, hidden);
+        ui::widgets::render_commands(frame, main_layout[3], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::Refresh => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::ReadRequestsRequested))?;
+                Ok(())
+            }
+            Action::UnmatchedOnly => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::ToggleUnmatchedOnly))?;
+                Ok(())
+            }
+            Action::StubRequest => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::StubSelectedRequested))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Journal(JournalMsg::SelectNext))?;
+                Ok(())
+            }
+            Action::ScrollUp => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::ScrollDetailsUp))?;
+                Ok(())
+            }
+            Action::ScrollDown => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::ScrollDetailsDown))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod journal_screen;
+pub mod model;
+pub mod stub_draft_screen;
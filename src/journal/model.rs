@@ -0,0 +1,391 @@
+use crate::model::{Command, GlobalMsg, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::stub::model::StubMsg;
+use crate::wire_mock;
+use crate::wire_mock::client::{
+    header_value, RequestPattern, ResponseDefinition, ServeEvent, WireMockStub,
+};
+use async_trait::async_trait;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::error::Error;
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DraftField {
+    Url,
+    Status,
+    Body,
+    Headers,
+}
+
+#[derive(Clone, Debug)]
+pub struct DraftHeader {
+    pub name: String,
+    pub value: String,
+    pub checked: bool,
+}
+
+/// A stub being prepared from a logged request.
+#[derive(Clone, Debug)]
+pub struct StubDraft {
+    pub method: String,
+    pub url: String,
+    pub status: String,
+    pub body: String,
+    pub content_type: Option<String>,
+    pub headers: Vec<DraftHeader>,
+    pub focused_field: DraftField,
+    pub selected_header_index: usize,
+}
+
+impl StubDraft {
+    /// Pre-fills the draft from a served request, taking status and body
+    /// from the upstream response when the request was proxied.
+    pub fn from_event(event: &ServeEvent) -> Self {
+        let proxied = event.response.as_ref().filter(|_| event.was_proxied());
+        let mut headers: Vec<DraftHeader> = event
+            .request
+            .headers
+            .iter()
+            .map(|(name, value)| DraftHeader {
+                name: name.clone(),
+                value: header_value(value),
+                checked: false,
+            })
+            .collect();
+        headers.sort_by(|a, b| a.name.cmp(&b.name));
+        Self {
+            method: event.request.method.clone(),
+            url: event.request.url.clone(),
+            status: proxied.map(|r| r.status).unwrap_or(200).to_string(),
+            body: proxied.and_then(|r| r.body.clone()).unwrap_or_default(),
+            content_type: proxied.and_then(|r| {
+                r.headers
+                    .iter()
+                    .find(|(name, _)| name.eq_ignore_ascii_case("content-type"))
+                    .map(|(_, value)| header_value(value))
+            }),
+            headers,
+            focused_field: DraftField::Url,
+            selected_header_index: 0,
+        }
+    }
+
+    pub fn to_stub(&self) -> Result<WireMockStub, JournalError> {
+        let status = self
+            .status
+            .trim()
+            .parse()
+            .map_err(|_| JournalError::InvalidStatus(self.status.clone()))?;
+        let mut request_extra = Map::new();
+        let header_matchers: Map<String, Value> = self
+            .headers
+            .iter()
+            .filter(|h| h.checked)
+            .map(|h| (h.name.clone(), json!({ "equalTo": h.value })))
+            .collect();
+        if !header_matchers.is_empty() {
+            request_extra.insert("headers".to_string(), Value::Object(header_matchers));
+        }
+        Ok(WireMockStub {
+            request: RequestPattern {
+                method: self.method.clone(),
+                url: Some(self.url.clone()),
+                url_pattern: None,
+                url_path: None,
+                url_path_pattern: None,
+                extra: request_extra,
+            },
+            response: ResponseDefinition {
+                status,
+                body: if self.body.is_empty() {
+                    None
+                } else {
+                    Some(self.body.clone())
+                },
+                headers: self
+                    .content_type
+                    .as_ref()
+                    .map(|c| HashMap::from([("Content-Type".to_string(), c.clone())])),
+                extra: Map::new(),
+            },
+        })
+    }
+
+    fn focused_input(&mut self) -> Option<&mut String> {
+        match self.focused_field {
+            DraftField::Url => Some(&mut self.url),
+            DraftField::Status => Some(&mut self.status),
+            DraftField::Body => Some(&mut self.body),
+            DraftField::Headers => None,
+        }
+    }
+}
+
+pub struct JournalModel {
+    pub selected_server_url: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub requests: Vec<ServeEvent>,
+    pub unmatched_only: bool,
+    pub selected_request_index: usize,
+    pub scroll_offset: usize,
+    pub draft: Option<StubDraft>,
+    pub status_message: Option<String>,
+    /// Outcome of the last action, shown ahead of the next journal summary
+    /// so reading the journal does not hide it.
+    pending_status: Option<String>,
+}
+
+#[async_trait]
+impl ModelTrait<JournalMsg, JournalCommand> for JournalModel {
+    async fn apply_event(&mut self, event: JournalMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            JournalMsg::SelectNext => {
+                let count = self.visible_requests().len();
+                if count > 0 {
+                    self.selected_request_index = (self.selected_request_index + 1).min(count - 1);
+                }
+                self.scroll_offset = 0;
+                Ok(())
+            }
+            JournalMsg::SelectPrevious => {
+                self.selected_request_index = self.selected_request_index.saturating_sub(1);
+                self.scroll_offset = 0;
+                Ok(())
+            }
+            JournalMsg::ScrollDetailsUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                Ok(())
+            }
+            JournalMsg::ScrollDetailsDown => {
+                self.scroll_offset += 1;
+                Ok(())
+            }
+            JournalMsg::ToggleUnmatchedOnly => {
+                self.set_unmatched_only(!self.unmatched_only);
+                Ok(())
+            }
+            JournalMsg::SetUnmatchedOnly(unmatched_only) => {
+                self.set_unmatched_only(unmatched_only);
+                Ok(())
+            }
+            JournalMsg::ReadRequestsRequested => {
+                self.command_sender
+                    .send(Command::Journal(JournalCommand::ReadRequests))?;
+                Ok(())
+            }
+            JournalMsg::StubSelectedRequested => {
+                if self.is_read_only() {
+                    self.status_message =
+                        Some("Read-only server: creating stubs is disabled".to_string());
+                    return Ok(());
+                }
+                if let Some(event) = self.selected_request() {
+                    self.draft = Some(StubDraft::from_event(event));
+                    self.event_sender
+                        .send(Message::Global(GlobalMsg::SwitchToStubDraftScreen))?;
+                }
+                Ok(())
+            }
+            JournalMsg::DraftFocusNextField => {
+                if let Some(draft) = self.draft.as_mut() {
+                    draft.focused_field = match draft.focused_field {
+                        DraftField::Url => DraftField::Status,
+                        DraftField::Status => DraftField::Body,
+                        DraftField::Body => DraftField::Headers,
+                        DraftField::Headers => DraftField::Url,
+                    };
+                }
+                Ok(())
+            }
+            JournalMsg::DraftInputChar(c) => {
+                // Only the body spans several lines
+                if let Some(draft) = self.draft.as_mut()
+                    && (c != '\n' || draft.focused_field == DraftField::Body)
+                {
+                    match draft.focused_input() {
+                        Some(input) => input.push(c),
+                        None if c == ' ' => {
+                            if let Some(header) = draft.headers.get_mut(draft.selected_header_index)
+                            {
+                                header.checked = !header.checked;
+                            }
+                        }
+                        None => {}
+                    }
+                }
+                Ok(())
+            }
+            JournalMsg::DraftInputBackspace => {
+                if let Some(input) = self.draft.as_mut().and_then(|d| d.focused_input()) {
+                    input.pop();
+                }
+                Ok(())
+            }
+            JournalMsg::DraftSelectNextHeader => {
+                if let Some(draft) = self.draft.as_mut()
+                    && !draft.headers.is_empty()
+                {
+                    draft.selected_header_index =
+                        (draft.selected_header_index + 1).min(draft.headers.len() - 1);
+                }
+                Ok(())
+            }
+            JournalMsg::DraftSelectPreviousHeader => {
+                if let Some(draft) = self.draft.as_mut() {
+                    draft.selected_header_index = draft.selected_header_index.saturating_sub(1);
+                }
+                Ok(())
+            }
+            JournalMsg::CreateStubRequested => {
+                self.command_sender
+                    .send(Command::Journal(JournalCommand::CreateStubFromDraft))?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: JournalCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            JournalCommand::ReadRequests => self.read_requests(),
+            JournalCommand::CreateStubFromDraft => self.create_stub_from_draft(),
+        };
+        if let Err(err) = result {
+            self.show_status(err.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl JournalModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            selected_server_url: None,
+            read_only_servers,
+            event_sender,
+            command_sender,
+            requests: vec![],
+            unmatched_only: false,
+            selected_request_index: 0,
+            scroll_offset: 0,
+            draft: None,
+            status_message: None,
+            pending_status: None,
+        }
+    }
+
+    /// Whether the selected server must not be changed.
+    pub fn is_read_only(&self) -> bool {
+        self.selected_server_url
+            .as_ref()
+            .is_some_and(|url| self.read_only_servers.contains(url))
+    }
+
+    pub fn visible_requests(&self) -> Vec<&ServeEvent> {
+        self.requests
+            .iter()
+            .filter(|r| !self.unmatched_only || !r.was_matched)
+            .collect()
+    }
+
+    pub fn selected_request(&self) -> Option<&ServeEvent> {
+        self.visible_requests()
+            .get(self.selected_request_index)
+            .copied()
+    }
+
+    fn set_unmatched_only(&mut self, unmatched_only: bool) {
+        self.unmatched_only = unmatched_only;
+        self.selected_request_index = 0;
+        self.scroll_offset = 0;
+    }
+
+    /// Shows `text`, after the outcome of the last action if that is still
+    /// pending.
+    fn show_status(&mut self, text: String) {
+        self.status_message = Some(match self.pending_status.take() {
+            Some(status) => format!("{}, {}", status, text),
+            None => text,
+        });
+    }
+
+    fn read_requests(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .as_ref()
+            .ok_or(JournalError::NoServerSelected)?;
+        self.requests = wire_mock::client::get_requests(server_url)?.requests;
+        self.selected_request_index = self
+            .selected_request_index
+            .min(self.visible_requests().len().saturating_sub(1));
+        let unmatched = self.requests.iter().filter(|r| !r.was_matched).count();
+        self.show_status(format!(
+            "{} requests, {} unmatched",
+            self.requests.len(),
+            unmatched
+        ));
+        Ok(())
+    }
+
+    fn create_stub_from_draft(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .as_ref()
+            .ok_or(JournalError::NoServerSelected)?;
+        self.read_only_servers.check(server_url, "creating stubs")?;
+        let Some(draft) = &self.draft else {
+            return Ok(());
+        };
+        let created = wire_mock::client::create_stub(server_url, &draft.to_stub()?)?;
+        self.pending_status = Some(format!(
+            "Created stub {} for {} {}",
+            created.id, draft.method, draft.url
+        ));
+        self.draft = None;
+        self.event_sender
+            .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+        self.event_sender
+            .send(Message::Global(GlobalMsg::SwitchToJournalScreen))?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum JournalCommand {
+    ReadRequests,
+    CreateStubFromDraft,
+}
+
+#[derive(Clone, Debug)]
+pub enum JournalMsg {
+    SelectNext,
+    SelectPrevious,
+    ScrollDetailsUp,
+    ScrollDetailsDown,
+    ToggleUnmatchedOnly,
+    SetUnmatchedOnly(bool),
+    ReadRequestsRequested,
+    StubSelectedRequested,
+    DraftFocusNextField,
+    DraftInputChar(char),
+    DraftInputBackspace,
+    DraftSelectNextHeader,
+    DraftSelectPreviousHeader,
+    CreateStubRequested,
+}
+
+#[derive(Error, Debug)]
+pub enum JournalError {
+    #[error("No server selected")]
+    NoServerSelected,
+    #[error("Invalid response status: {0}")]
+    InvalidStatus(String),
+}
@@ -0,0 +1,221 @@
+use crate::journal::model::{DraftField, JournalMsg, StubDraft};
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::ui;
+use crate::ui::theme::Theme;
+use async_trait::async_trait;
+use crossterm::event::{Event, KeyCode};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::Style;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct StubDraftScreen {
+    sender: Sender<Message>,
+}
+
+impl StubDraftScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        StubDraftScreen { sender }
+    }
+
+    fn render_input(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        draft: &StubDraft,
+        theme: &Theme,
+        field: DraftField,
+        title: &str,
+    ) {
+        let value = match field {
+            DraftField::Url => &draft.url,
+            DraftField::Status => &draft.status,
+            DraftField::Body => &draft.body,
+            DraftField::Headers => return,
+        };
+        let (text, style) = if draft.focused_field == field {
+            (format!("{}█", value), theme.highlight())
+        } else {
+            (value.clone(), Style::default())
+        };
+        let input = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(style)
+                .title(title.to_string()),
+        );
+        frame.render_widget(input, area);
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for StubDraftScreen {
+    fn scope(&self) -> Scope {
+        Scope::StubDraft
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock - Stub This Request", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        let Some(draft) = &app.journal_model.draft else {
+            return;
+        };
+
+        // Method, url and status
+        let request_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![
+                Constraint::Length(10),
+                Constraint::Min(0),
+                Constraint::Length(10),
+            ])
+            .split(main_layout[1]);
+        let method = Paragraph::new(draft.method.as_str())
+            .block(Block::default().borders(Borders::ALL).title("Method"));
+        frame.render_widget(method, request_layout[0]);
+        self.render_input(
+            frame,
+            request_layout[1],
+            draft,
+            &app.theme,
+            DraftField::Url,
+            "Exact URL",
+        );
+        self.render_input(
+            frame,
+            request_layout[2],
+            draft,
+            &app.theme,
+            DraftField::Status,
+            "Status",
+        );
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(main_layout[2]);
+
+        // Header matchers (left side)
+        let items: Vec<ListItem> = draft
+            .headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| {
+                let check = if header.checked { "[x]" } else { "[ ]" };
+                let style = if draft.focused_field == DraftField::Headers
+                    && i == draft.selected_header_index
+                {
+                    app.theme.selected()
+                } else {
+                    app.theme.text()
+                };
+                ListItem::new(format!("{} {}: {}", check, header.name, header.value)).style(style)
+            })
+            .collect();
+        let border_style = if draft.focused_field == DraftField::Headers {
+            app.theme.highlight()
+        } else {
+            Style::default()
+        };
+        let headers = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(border_style)
+                .title("Match headers"),
+        );
+        frame.render_widget(headers, content_layout[0]);
+
+        // Response body (right side)
+        let body_title = match &draft.content_type {
+            Some(content_type) => format!("Response body ({})", content_type),
+            None => "Response body".to_string(),
+        };
+        self.render_input(
+            frame,
+            content_layout[1],
+            draft,
+            &app.theme,
+            DraftField::Body,
+            &body_title,
+        );
+
+        // Status
+        let status = Paragraph::new(app.journal_model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::StubDraft, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToJournalScreen))?;
+                Ok(())
+            }
+            Action::CreateStub => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::CreateStubRequested))?;
+                Ok(())
+            }
+            Action::NextField => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::DraftFocusNextField))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::DraftSelectPreviousHeader))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender
+                    .send(Message::Journal(JournalMsg::DraftSelectNextHeader))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(c) => {
+                    self.sender
+                        .send(Message::Journal(JournalMsg::DraftInputChar(c)))?;
+                    Ok(())
+                }
+                KeyCode::Enter => {
+                    self.sender
+                        .send(Message::Journal(JournalMsg::DraftInputChar('\n')))?;
+                    Ok(())
+                }
+                KeyCode::Backspace => {
+                    self.sender
+                        .send(Message::Journal(JournalMsg::DraftInputBackspace))?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
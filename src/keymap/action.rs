@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+/// The screens that have their own key bindings, named as in the `keys`
+/// section of the configuration file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    ServerSelection,
+    ServerEdit,
+    Stubs,
+    Journal,
+    StubDraft,
+    Import,
+    Compare,
+    Transfer,
+    Sync,
+    OpenApi,
+    Har,
+    Tester,
+    Lint,
+    Coverage,
+    Dashboard,
+    Admin,
+    Help,
+    Palette,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 18] = [
+        Scope::ServerSelection,
+        Scope::ServerEdit,
+        Scope::Stubs,
+        Scope::Journal,
+        Scope::StubDraft,
+        Scope::Import,
+        Scope::Compare,
+        Scope::Transfer,
+        Scope::Sync,
+        Scope::OpenApi,
+        Scope::Har,
+        Scope::Tester,
+        Scope::Lint,
+        Scope::Coverage,
+        Scope::Dashboard,
+        Scope::Admin,
+        Scope::Help,
+        Scope::Palette,
+    ];
+
+    /// Screens with text fields, where printable keys are typed rather than
+    /// bound.
+    pub fn takes_text(&self) -> bool {
+        matches!(
+            self,
+            Scope::StubDraft
+                | Scope::Import
+                | Scope::Sync
+                | Scope::OpenApi
+                | Scope::Har
+                | Scope::Tester
+                | Scope::Palette
+        )
+    }
+}
+
+/// Everything a key can be bound to. Each screen handles the actions listed
+/// for its scope in the default bindings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Help,
+    CommandPalette,
+    Quit,
+    Back,
+    Up,
+    Down,
+    ScrollUp,
+    ScrollDown,
+    Refresh,
+    Confirm,
+    NextField,
+    // Server selection
+    EditServer,
+    CompareServers,
+    // Stubs
+    Delete,
+    Mark,
+    CopyToServer,
+    MoveToServer,
+    CopyAs,
+    RequestJournal,
+    TestRequest,
+    LintReport,
+    Coverage,
+    LiveTraffic,
+    AlertMode,
+    Housekeeping,
+    Import,
+    FromOpenApi,
+    FromHar,
+    WatchDirectory,
+    AutoRefresh,
+    // Journal
+    UnmatchedOnly,
+    StubRequest,
+    CreateStub,
+    // Import, OpenAPI and HAR
+    Preview,
+    DuplicatePolicy,
+    Generate,
+    Check,
+    CheckAll,
+    Load,
+    MatchQuery,
+    MatchBody,
+    Duplicates,
+    Push,
+    // Compare and transfer
+    PickLeftServer,
+    PickRightServer,
+    Compare,
+    SyncToRight,
+    SyncToLeft,
+    KeepIds,
+    Transfer,
+    // Sync, tester, lint and housekeeping
+    ToggleWatch,
+    ForcePush,
+    Send,
+    Simulate,
+    GoToStub,
+    Run,
+}
+
+impl Action {
+    /// The heading the action is listed under in the help overlay.
+    pub fn group(&self, scope: Scope) -> &'static str {
+        match self {
+            Action::Help | Action::CommandPalette | Action::Quit | Action::Back => "General",
+            Action::Up
+            | Action::Down
+            | Action::ScrollUp
+            | Action::ScrollDown
+            | Action::NextField
+            | Action::PickLeftServer
+            | Action::PickRightServer => "Navigation",
+            // Importing opens the import screen from the stub list
+            Action::Import if scope == Scope::Stubs => "Screens",
+            Action::EditServer
+            | Action::CompareServers
+            | Action::CopyToServer
+            | Action::MoveToServer
+            | Action::RequestJournal
+            | Action::TestRequest
+            | Action::LintReport
+            | Action::Coverage
+            | Action::LiveTraffic
+            | Action::Housekeeping
+            | Action::FromOpenApi
+            | Action::FromHar
+            | Action::WatchDirectory
+            | Action::StubRequest => "Screens",
+            _ => "Actions",
+        }
+    }
+}
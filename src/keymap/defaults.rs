@@ -0,0 +1,602 @@
+use crate::keymap::action::{Action, Scope};
+
+/// A binding every screen starts with.
+pub struct DefaultBinding {
+    pub action: Action,
+    pub keys: &'static [&'static str],
+    /// Short name shown in the footer.
+    pub label: &'static str,
+    /// What the action does, shown in the help overlay.
+    pub description: &'static str,
+    /// Whether the footer shows the binding when there is room for it.
+    pub in_footer: bool,
+}
+
+/// A binding important enough for the footer.
+fn primary(
+    action: Action,
+    keys: &'static [&'static str],
+    label: &'static str,
+    description: &'static str,
+) -> DefaultBinding {
+    DefaultBinding {
+        action,
+        keys,
+        label,
+        description,
+        in_footer: true,
+    }
+}
+
+/// A binding only listed in the help overlay.
+fn secondary(
+    action: Action,
+    keys: &'static [&'static str],
+    label: &'static str,
+    description: &'static str,
+) -> DefaultBinding {
+    DefaultBinding {
+        in_footer: false,
+        ..primary(action, keys, label, description)
+    }
+}
+
+/// The bindings every screen starts with, in footer order. Help is bound on
+/// every screen in addition to these.
+pub fn default_bindings(scope: Scope) -> Vec<DefaultBinding> {
+    match scope {
+        Scope::ServerSelection => vec![
+            secondary(Action::Up, &["up", "k"], "Up", "Select the previous server"),
+            secondary(
+                Action::Down,
+                &["down", "j"],
+                "Down",
+                "Select the next server",
+            ),
+            primary(
+                Action::Confirm,
+                &["enter"],
+                "Confirm",
+                "Open the stubs of the selected server",
+            ),
+            primary(
+                Action::EditServer,
+                &["e"],
+                "Edit server connection",
+                "Edit the server connection",
+            ),
+            primary(
+                Action::CompareServers,
+                &["c"],
+                "Compare servers",
+                "Compare the stubs of two servers",
+            ),
+            primary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::ServerEdit => vec![
+            primary(
+                Action::Confirm,
+                &["enter"],
+                "Confirm",
+                "Return to the server selection",
+            ),
+            primary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::Stubs => vec![
+            secondary(Action::Up, &["up", "k"], "Up", "Select the previous stub"),
+            secondary(Action::Down, &["down", "j"], "Down", "Select the next stub"),
+            secondary(
+                Action::ScrollUp,
+                &["pgup"],
+                "Scroll up",
+                "Scroll the stub details up",
+            ),
+            secondary(
+                Action::ScrollDown,
+                &["pgdn"],
+                "Scroll down",
+                "Scroll the stub details down",
+            ),
+            primary(
+                Action::Refresh,
+                &["r"],
+                "Refresh",
+                "Reload the stubs from the server",
+            ),
+            primary(Action::Delete, &["d"], "Delete", "Delete the selected stub"),
+            primary(
+                Action::Mark,
+                &["space"],
+                "Mark",
+                "Mark or unmark the selected stub for copying or moving",
+            ),
+            primary(
+                Action::CopyToServer,
+                &["c"],
+                "Copy to server",
+                "Copy the marked stubs to another server",
+            ),
+            secondary(
+                Action::MoveToServer,
+                &["m"],
+                "Move to server",
+                "Move the marked stubs to another server",
+            ),
+            secondary(
+                Action::CopyAs,
+                &["y"],
+                "Copy as…",
+                "Copy the selected stub as JSON or curl to the clipboard",
+            ),
+            secondary(Action::Back, &["esc"], "Close", "Close the copy menu"),
+            primary(
+                Action::RequestJournal,
+                &["l"],
+                "Request journal",
+                "Browse the requests the server received",
+            ),
+            primary(
+                Action::TestRequest,
+                &["t"],
+                "Test request",
+                "Send a test request and see which stub answers",
+            ),
+            secondary(
+                Action::LintReport,
+                &["e"],
+                "Lint report",
+                "List problems found in the stubs",
+            ),
+            secondary(
+                Action::Coverage,
+                &["v"],
+                "Coverage",
+                "Show how often each stub was hit",
+            ),
+            secondary(
+                Action::LiveTraffic,
+                &["g"],
+                "Live traffic",
+                "Watch request rates and status codes live",
+            ),
+            secondary(
+                Action::AlertMode,
+                &["n"],
+                "Alert mode",
+                "Cycle how unmatched requests are announced",
+            ),
+            secondary(
+                Action::Housekeeping,
+                &["x"],
+                "Housekeeping",
+                "Reset, save or shut down the server",
+            ),
+            primary(Action::Import, &["i"], "Import", "Import stubs from files"),
+            secondary(
+                Action::FromOpenApi,
+                &["o"],
+                "From OpenAPI",
+                "Generate stubs from an OpenAPI specification",
+            ),
+            secondary(
+                Action::FromHar,
+                &["h"],
+                "From HAR",
+                "Generate stubs from a HAR recording",
+            ),
+            secondary(
+                Action::WatchDirectory,
+                &["w"],
+                "Watch & sync dir",
+                "Keep the server in sync with a directory of stub files",
+            ),
+            secondary(
+                Action::AutoRefresh,
+                &["a"],
+                "Toggle auto refresh",
+                "Reload the stubs every second",
+            ),
+            primary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::Journal => vec![
+            secondary(
+                Action::Up,
+                &["up", "k"],
+                "Up",
+                "Select the previous request",
+            ),
+            secondary(
+                Action::Down,
+                &["down", "j"],
+                "Down",
+                "Select the next request",
+            ),
+            secondary(
+                Action::ScrollUp,
+                &["pgup"],
+                "Scroll up",
+                "Scroll the request details up",
+            ),
+            secondary(
+                Action::ScrollDown,
+                &["pgdn"],
+                "Scroll down",
+                "Scroll the request details down",
+            ),
+            primary(
+                Action::Refresh,
+                &["r"],
+                "Refresh",
+                "Reload the request journal",
+            ),
+            primary(
+                Action::UnmatchedOnly,
+                &["u"],
+                "Unmatched only",
+                "Show only requests no stub matched",
+            ),
+            primary(
+                Action::StubRequest,
+                &["s"],
+                "Stub this request",
+                "Draft a stub answering the selected request",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+            secondary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::StubDraft => vec![
+            primary(
+                Action::NextField,
+                &["tab"],
+                "Next field",
+                "Move to the next field",
+            ),
+            secondary(
+                Action::Up,
+                &["up"],
+                "Previous header",
+                "Select the previous request header",
+            ),
+            secondary(
+                Action::Down,
+                &["down"],
+                "Next header",
+                "Select the next request header",
+            ),
+            primary(
+                Action::CreateStub,
+                &["ctrl+s"],
+                "Create stub",
+                "Create the stub on the server",
+            ),
+            primary(
+                Action::Back,
+                &["esc"],
+                "Cancel",
+                "Discard the draft and return to the journal",
+            ),
+        ],
+        Scope::Import => vec![
+            primary(
+                Action::Preview,
+                &["enter"],
+                "Preview",
+                "Read the stubs from the given path",
+            ),
+            primary(
+                Action::DuplicatePolicy,
+                &["tab"],
+                "Duplicate policy",
+                "Choose how stubs that already exist are handled",
+            ),
+            primary(
+                Action::Import,
+                &["ctrl+s"],
+                "Import",
+                "Import the previewed stubs",
+            ),
+            secondary(Action::Up, &["up"], "Up", "Select the previous stub"),
+            secondary(Action::Down, &["down"], "Down", "Select the next stub"),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+        ],
+        Scope::Compare => vec![
+            primary(
+                Action::PickLeftServer,
+                &["1"],
+                "Pick left server",
+                "Cycle the server on the left",
+            ),
+            primary(
+                Action::PickRightServer,
+                &["2"],
+                "Pick right server",
+                "Cycle the server on the right",
+            ),
+            primary(
+                Action::Compare,
+                &["enter"],
+                "Compare",
+                "Compare the stubs of both servers",
+            ),
+            secondary(
+                Action::Up,
+                &["up", "k"],
+                "Up",
+                "Select the previous difference",
+            ),
+            secondary(
+                Action::Down,
+                &["down", "j"],
+                "Down",
+                "Select the next difference",
+            ),
+            secondary(
+                Action::ScrollUp,
+                &["pgup"],
+                "Scroll up",
+                "Scroll the diff up",
+            ),
+            secondary(
+                Action::ScrollDown,
+                &["pgdn"],
+                "Scroll down",
+                "Scroll the diff down",
+            ),
+            primary(
+                Action::SyncToRight,
+                &[">"],
+                "Sync to right",
+                "Make the right server match the left for this stub",
+            ),
+            primary(
+                Action::SyncToLeft,
+                &["<"],
+                "Sync to left",
+                "Make the left server match the right for this stub",
+            ),
+            primary(Action::Confirm, &["y"], "Confirm", "Confirm a sync"),
+            primary(
+                Action::Back,
+                &["esc"],
+                "Back",
+                "Cancel the confirmation or return to the server selection",
+            ),
+            secondary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::Transfer => vec![
+            secondary(
+                Action::Up,
+                &["up", "k"],
+                "Up",
+                "Select the previous target server",
+            ),
+            secondary(
+                Action::Down,
+                &["down", "j"],
+                "Down",
+                "Select the next target server",
+            ),
+            primary(
+                Action::KeepIds,
+                &["i"],
+                "Keep/regenerate ids",
+                "Choose whether stubs keep their ids on the target",
+            ),
+            primary(
+                Action::Transfer,
+                &["enter"],
+                "Transfer",
+                "Copy or move the stubs to the target server",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+            secondary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::Sync => vec![
+            primary(
+                Action::ToggleWatch,
+                &["enter"],
+                "Start/stop watching",
+                "Start or stop syncing the directory",
+            ),
+            primary(
+                Action::ForcePush,
+                &["ctrl+f"],
+                "Force push conflicts",
+                "Overwrite conflicting stubs on the server with the files",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+        ],
+        Scope::OpenApi => vec![
+            primary(
+                Action::Generate,
+                &["enter"],
+                "Generate",
+                "Generate stubs from the specification",
+            ),
+            secondary(Action::Up, &["up"], "Up", "Select the previous stub"),
+            secondary(Action::Down, &["down"], "Down", "Select the next stub"),
+            primary(
+                Action::Check,
+                &["tab"],
+                "Check",
+                "Check or uncheck the selected stub",
+            ),
+            secondary(
+                Action::CheckAll,
+                &["ctrl+a"],
+                "Check all",
+                "Check or uncheck every stub",
+            ),
+            primary(
+                Action::Push,
+                &["ctrl+s"],
+                "Push checked",
+                "Create the checked stubs on the server",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+        ],
+        Scope::Har => vec![
+            primary(
+                Action::Load,
+                &["enter"],
+                "Load",
+                "Load the recording and generate stubs",
+            ),
+            primary(
+                Action::NextField,
+                &["tab"],
+                "Next field",
+                "Move to the next field",
+            ),
+            secondary(Action::Up, &["up"], "Up", "Select the previous stub"),
+            secondary(Action::Down, &["down"], "Down", "Select the next stub"),
+            secondary(
+                Action::MatchQuery,
+                &["ctrl+q"],
+                "Match query",
+                "Match query parameters in generated stubs",
+            ),
+            secondary(
+                Action::MatchBody,
+                &["ctrl+b"],
+                "Match body",
+                "Match request bodies in generated stubs",
+            ),
+            secondary(
+                Action::Duplicates,
+                &["ctrl+d"],
+                "Duplicates",
+                "Cycle how repeated requests are turned into stubs",
+            ),
+            primary(
+                Action::Push,
+                &["ctrl+s"],
+                "Push",
+                "Import the generated stubs into the server",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+        ],
+        Scope::Tester => vec![
+            primary(
+                Action::NextField,
+                &["tab"],
+                "Next field",
+                "Move to the next field",
+            ),
+            primary(
+                Action::Send,
+                &["ctrl+s"],
+                "Send",
+                "Send the request to the server",
+            ),
+            primary(
+                Action::Simulate,
+                &["ctrl+e"],
+                "Simulate offline",
+                "Match the request against the stubs without sending it",
+            ),
+            secondary(
+                Action::ScrollUp,
+                &["pgup"],
+                "Scroll response up",
+                "Scroll the response up",
+            ),
+            secondary(
+                Action::ScrollDown,
+                &["pgdn"],
+                "Scroll response down",
+                "Scroll the response down",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+        ],
+        Scope::Lint => vec![
+            secondary(
+                Action::Up,
+                &["up", "k"],
+                "Up",
+                "Select the previous finding",
+            ),
+            secondary(
+                Action::Down,
+                &["down", "j"],
+                "Down",
+                "Select the next finding",
+            ),
+            primary(
+                Action::GoToStub,
+                &["enter"],
+                "Go to stub",
+                "Show the stub of the selected finding",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+            secondary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::Coverage => vec![
+            primary(
+                Action::Refresh,
+                &["r"],
+                "Refresh",
+                "Reload the stubs and hit counts",
+            ),
+            primary(Action::Back, &["esc"], "Back", "Return to the stubs"),
+            primary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::Dashboard => vec![
+            primary(
+                Action::Back,
+                &["esc"],
+                "Back",
+                "Stop watching and return to the stubs",
+            ),
+            primary(Action::Quit, &["q"], "Quit", "Quit the application"),
+        ],
+        Scope::Admin => vec![
+            secondary(Action::Up, &["up", "k"], "Up", "Select the previous action"),
+            secondary(
+                Action::Down,
+                &["down", "j"],
+                "Down",
+                "Select the next action",
+            ),
+            primary(Action::Run, &["enter"], "Run", "Run the selected action"),
+            primary(
+                Action::Confirm,
+                &["y"],
+                "Confirm",
+                "Confirm a destructive action",
+            ),
+            primary(
+                Action::Back,
+                &["esc", "n"],
+                "Back",
+                "Cancel the confirmation or return to the stubs",
+            ),
+        ],
+        Scope::Help => vec![
+            secondary(Action::Up, &["up", "k"], "Up", "Scroll up"),
+            secondary(Action::Down, &["down", "j"], "Down", "Scroll down"),
+            primary(Action::Back, &["esc", "q"], "Close", "Close the help"),
+        ],
+        Scope::Palette => vec![
+            secondary(
+                Action::Up,
+                &["up", "ctrl+k"],
+                "Up",
+                "Select the previous command",
+            ),
+            secondary(
+                Action::Down,
+                &["down", "ctrl+j"],
+                "Down",
+                "Select the next command",
+            ),
+            primary(
+                Action::Confirm,
+                &["enter"],
+                "Run",
+                "Run the selected command",
+            ),
+            primary(Action::Back, &["esc"], "Close", "Close the command palette"),
+        ],
+    }
+}
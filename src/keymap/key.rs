@@ -0,0 +1,245 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// One key press together with its modifiers, such as `ctrl+s` or `k`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        match code {
+            // Shift is already part of a typed character, so `>` arrives with
+            // or without it depending on the terminal
+            KeyCode::Char(_) => KeyChord {
+                code,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::BackTab => KeyChord {
+                code: KeyCode::Tab,
+                modifiers: modifiers | KeyModifiers::SHIFT,
+            },
+            code => KeyChord { code, modifiers },
+        }
+    }
+}
+
+impl From<&KeyEvent> for KeyChord {
+    fn from(event: &KeyEvent) -> Self {
+        KeyChord::new(event.code, event.modifiers)
+    }
+}
+
+impl FromStr for KeyChord {
+    type Err = KeyParseError;
+
+    /// Parses chords like `k`, `ctrl+s`, `shift+tab`, `pgdn` or `f5`.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || KeyParseError(text.to_string());
+        // A trailing `+` is the plus key itself, as in `ctrl++`
+        let (prefix, key) = match text.strip_suffix("++") {
+            Some(prefix) => (Some(prefix), "+"),
+            None if text == "+" => (None, "+"),
+            None => match text.rsplit_once('+') {
+                Some((prefix, key)) => (Some(prefix), key),
+                None => (None, text),
+            },
+        };
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in prefix.into_iter().flat_map(|p| p.split('+')) {
+            modifiers |= match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid()),
+            };
+        }
+        let code = match key.to_ascii_lowercase().as_str() {
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "pgup" | "pageup" => KeyCode::PageUp,
+            "pgdn" | "pagedown" => KeyCode::PageDown,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "tab" => KeyCode::Tab,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "del" | "delete" => KeyCode::Delete,
+            name if name.len() > 1 && name.starts_with('f') => {
+                KeyCode::F(name[1..].parse().map_err(|_| invalid())?)
+            }
+            _ => {
+                let mut chars = key.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) if modifiers.contains(KeyModifiers::SHIFT) => {
+                        KeyCode::Char(c.to_ascii_uppercase())
+                    }
+                    (Some(c), None) => KeyCode::Char(c),
+                    _ => return Err(invalid()),
+                }
+            }
+        };
+        Ok(KeyChord::new(code, modifiers))
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::PageUp => write!(f, "PgUp"),
+            KeyCode::PageDown => write!(f, "PgDn"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Del"),
+            KeyCode::F(n) => write!(f, "F{}", n),
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) if self.modifiers.is_empty() => write!(f, "{}", c),
+            KeyCode::Char(c) if c.is_ascii_uppercase() => write!(f, "Shift+{}", c),
+            KeyCode::Char(c) => write!(f, "{}", c.to_ascii_uppercase()),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
+
+/// Chords pressed one after another, written space separated like `g g`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeySequence(pub Vec<KeyChord>);
+
+impl FromStr for KeySequence {
+    type Err = KeyParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let chords = text
+            .split_whitespace()
+            .map(KeyChord::from_str)
+            .collect::<Result<Vec<_>, _>>()?;
+        if chords.is_empty() {
+            return Err(KeyParseError(text.to_string()));
+        }
+        Ok(KeySequence(chords))
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let chords: Vec<String> = self.0.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}", chords.join(" "))
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Invalid key {0:?}")]
+pub struct KeyParseError(pub String);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chord(text: &str) -> KeyChord {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_plain_and_named_keys() {
+        assert_eq!(
+            chord("k"),
+            KeyChord::new(KeyCode::Char('k'), KeyModifiers::NONE)
+        );
+        assert_eq!(chord("pgdn"), chord("PageDown"));
+        assert_eq!(
+            chord("esc"),
+            KeyChord::new(KeyCode::Esc, KeyModifiers::NONE)
+        );
+        assert_eq!(
+            chord("space"),
+            KeyChord::new(KeyCode::Char(' '), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            chord("f5"),
+            KeyChord::new(KeyCode::F(5), KeyModifiers::NONE)
+        );
+    }
+
+    #[test]
+    fn parses_modifiers() {
+        assert_eq!(
+            chord("ctrl+s"),
+            KeyChord::new(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            chord("Control+Alt+x"),
+            KeyChord::new(
+                KeyCode::Char('x'),
+                KeyModifiers::CONTROL | KeyModifiers::ALT
+            )
+        );
+        assert_eq!(
+            chord("ctrl++"),
+            KeyChord::new(KeyCode::Char('+'), KeyModifiers::CONTROL)
+        );
+        assert_eq!(
+            chord("+"),
+            KeyChord::new(KeyCode::Char('+'), KeyModifiers::NONE)
+        );
+    }
+
+    #[test]
+    fn shift_is_part_of_typed_characters() {
+        assert_eq!(
+            chord("shift+g"),
+            KeyChord::new(KeyCode::Char('G'), KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyChord::new(KeyCode::Char('>'), KeyModifiers::SHIFT),
+            chord(">")
+        );
+        assert_eq!(
+            KeyChord::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+            chord("shift+tab")
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!("hyper+k".parse::<KeyChord>().is_err());
+        assert!("nope".parse::<KeyChord>().is_err());
+        assert!("fx".parse::<KeyChord>().is_err());
+        assert!("".parse::<KeySequence>().is_err());
+        assert!("g nope".parse::<KeySequence>().is_err());
+    }
+
+    #[test]
+    fn parses_and_shows_sequences() {
+        let sequence: KeySequence = "g  g".parse().unwrap();
+        assert_eq!(sequence.0, vec![chord("g"), chord("g")]);
+        assert_eq!(sequence.to_string(), "g g");
+        assert_eq!(chord("ctrl+s").to_string(), "Ctrl+S");
+        assert_eq!(chord("shift+g").to_string(), "G");
+        assert_eq!(chord("up").to_string(), "↑");
+    }
+}
@@ -0,0 +1,341 @@
+pub mod action;
+pub mod defaults;
+pub mod key;
+
+use crate::keymap::action::{Action, Scope};
+use crate::keymap::key::{KeyChord, KeyParseError, KeySequence};
+use crossterm::event::KeyEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Key overrides from the configuration file, per screen and action.
+pub type KeyOverrides = HashMap<Scope, HashMap<Action, KeyList>>;
+
+/// One key sequence or several, as written in the configuration file.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn entries(&self) -> Vec<&str> {
+        match self {
+            KeyList::One(key) => vec![key],
+            KeyList::Many(keys) => keys.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Binding {
+    pub action: Action,
+    pub keys: Vec<KeySequence>,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub in_footer: bool,
+}
+
+impl Binding {
+    /// The keys as shown to the user, like `↑/k`.
+    pub fn keys_text(&self) -> String {
+        let keys: Vec<String> = self.keys.iter().map(|k| k.to_string()).collect();
+        keys.join("/")
+    }
+}
+
+/// What a key press amounts to on the current screen.
+#[derive(Debug, PartialEq)]
+pub enum KeyResolution {
+    Action(Action),
+    /// The key starts a sequence, so more keys are needed.
+    Pending,
+    /// No binding uses the key; text inputs take it as typed.
+    Unbound,
+}
+
+/// Every screen's key bindings. The registry drives both key handling and
+/// the footers, so the two cannot drift apart.
+pub struct Keymap {
+    scopes: HashMap<Scope, Vec<Binding>>,
+    pending: Vec<KeyChord>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Keymap::new(&KeyOverrides::new()).expect("default key bindings are valid")
+    }
+}
+
+impl Keymap {
+    /// Builds the default bindings with the user's overrides applied. An
+    /// override replaces the action's default keys and takes them away from
+    /// any other action on the same screen.
+    pub fn new(overrides: &KeyOverrides) -> Result<Self, KeymapError> {
+        let mut scopes = HashMap::new();
+        for scope in Scope::ALL {
+            let mut bindings: Vec<Binding> = global_bindings(scope)
+                .into_iter()
+                .chain(
+                    defaults::default_bindings(scope)
+                        .into_iter()
+                        .map(|default| Binding {
+                            action: default.action,
+                            keys: parse_defaults(default.keys),
+                            label: default.label,
+                            description: default.description,
+                            in_footer: default.in_footer,
+                        }),
+                )
+                .collect();
+            for (&action, keys) in overrides.get(&scope).into_iter().flatten() {
+                let keys = keys
+                    .entries()
+                    .into_iter()
+                    .map(str::parse)
+                    .collect::<Result<Vec<KeySequence>, KeyParseError>>()
+                    .map_err(|err| KeymapError::InvalidKey(scope, action, err))?;
+                for binding in bindings.iter_mut() {
+                    binding.keys.retain(|k| !keys.contains(k));
+                }
+                bindings
+                    .iter_mut()
+                    .find(|b| b.action == action)
+                    .ok_or(KeymapError::UnknownAction(scope, action))?
+                    .keys = keys;
+            }
+            scopes.insert(scope, bindings);
+        }
+        Ok(Keymap {
+            scopes,
+            pending: vec![],
+        })
+    }
+
+    pub fn bindings(&self, scope: Scope) -> &[Binding] {
+        self.scopes.get(&scope).map_or(&[], Vec::as_slice)
+    }
+
+    /// Feeds a key press to the keymap. Keys of a sequence are collected
+    /// until they complete a binding; a key that breaks the sequence starts
+    /// over on its own. A key that starts a longer sequence waits for it even
+    /// when it is also bound by itself.
+    pub fn resolve(&mut self, scope: Scope, event: &KeyEvent) -> KeyResolution {
+        let chord = KeyChord::from(event);
+        let mut typed = std::mem::take(&mut self.pending);
+        typed.push(chord);
+        match self.lookup(scope, &typed) {
+            KeyResolution::Unbound if typed.len() > 1 => self.resolve(scope, event),
+            KeyResolution::Pending => {
+                self.pending = typed;
+                KeyResolution::Pending
+            }
+            resolution => resolution,
+        }
+    }
+
+    /// Forgets a sequence typed halfway, so it cannot complete on the next
+    /// screen.
+    pub fn reset_pending(&mut self) {
+        self.pending.clear();
+    }
+
+    fn lookup(&self, scope: Scope, typed: &[KeyChord]) -> KeyResolution {
+        let mut exact = None;
+        for binding in self.bindings(scope) {
+            for KeySequence(keys) in &binding.keys {
+                if keys.len() > typed.len() && keys.starts_with(typed) {
+                    return KeyResolution::Pending;
+                }
+                if keys == typed {
+                    exact = Some(binding.action);
+                }
+            }
+        }
+        exact.map_or(KeyResolution::Unbound, KeyResolution::Action)
+    }
+
+    /// Keys and label of the screen's most important bindings for the
+    /// footer, leaving out `hidden`.
+    pub fn commands(&self, scope: Scope, hidden: &[Action]) -> Vec<(String, &'static str)> {
+        self.bindings(scope)
+            .iter()
+            .filter(|b| b.in_footer && !b.keys.is_empty() && !hidden.contains(&b.action))
+            .map(|b| (b.keys_text(), b.label))
+            .collect()
+    }
+}
+
+fn parse_defaults(keys: &[&str]) -> Vec<KeySequence> {
+    keys.iter()
+        .map(|k| k.parse().expect("default keys are valid"))
+        .collect()
+}
+
+/// Help and the command palette open from every screen; `?` and `:` only
+/// where they are not typed into a field.
+fn global_bindings(scope: Scope) -> Vec<Binding> {
+    let (help_keys, palette_keys): (&[&str], &[&str]) = if scope.takes_text() {
+        (&["f1"], &["ctrl+p"])
+    } else {
+        (&["?", "f1"], &[":", "ctrl+p"])
+    };
+    vec![
+        Binding {
+            action: Action::Help,
+            keys: parse_defaults(help_keys),
+            label: "Help",
+            description: "Show every key of this screen",
+            in_footer: true,
+        },
+        Binding {
+            action: Action::CommandPalette,
+            keys: parse_defaults(palette_keys),
+            label: "Commands",
+            description: "Search and run any command of the application",
+            in_footer: true,
+        },
+    ]
+}
+
+#[derive(Error, Debug)]
+pub enum KeymapError {
+    #[error("{2} for {1:?} on the {0:?} screen")]
+    InvalidKey(Scope, Action, KeyParseError),
+    #[error("{1:?} is not available on the {0:?} screen")]
+    UnknownAction(Scope, Action),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    fn press(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn keymap(overrides: &[(Action, &[&str])]) -> Keymap {
+        let overrides = HashMap::from([(
+            Scope::Stubs,
+            overrides
+                .iter()
+                .map(|(action, keys)| {
+                    let keys = keys.iter().map(|k| k.to_string()).collect();
+                    (*action, KeyList::Many(keys))
+                })
+                .collect(),
+        )]);
+        Keymap::new(&overrides).unwrap()
+    }
+
+    fn keys_of(keymap: &Keymap, action: Action) -> Vec<String> {
+        keymap
+            .bindings(Scope::Stubs)
+            .iter()
+            .find(|b| b.action == action)
+            .unwrap()
+            .keys
+            .iter()
+            .map(|k| k.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn resolves_default_keys() {
+        let mut keymap = Keymap::default();
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('r')),
+            KeyResolution::Action(Action::Refresh)
+        );
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('?')),
+            KeyResolution::Action(Action::Help)
+        );
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('%')),
+            KeyResolution::Unbound
+        );
+    }
+
+    #[test]
+    fn an_override_replaces_the_defaults_and_steals_its_keys() {
+        let keymap = keymap(&[(Action::Refresh, &["d", "ctrl+r"])]);
+        assert_eq!(keys_of(&keymap, Action::Refresh), ["d", "Ctrl+R"]);
+        assert!(keys_of(&keymap, Action::Delete).is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_overrides() {
+        let overrides = HashMap::from([(
+            Scope::Stubs,
+            HashMap::from([(Action::Refresh, KeyList::One("hyper+r".to_string()))]),
+        )]);
+        assert!(matches!(
+            Keymap::new(&overrides),
+            Err(KeymapError::InvalidKey(Scope::Stubs, Action::Refresh, _))
+        ));
+        let overrides = HashMap::from([(
+            Scope::Help,
+            HashMap::from([(Action::Delete, KeyList::One("x".to_string()))]),
+        )]);
+        assert!(matches!(
+            Keymap::new(&overrides),
+            Err(KeymapError::UnknownAction(Scope::Help, Action::Delete))
+        ));
+    }
+
+    #[test]
+    fn a_sequence_waits_for_its_remaining_keys() {
+        let mut keymap = keymap(&[(Action::Refresh, &["g r"]), (Action::Delete, &["g"])]);
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('g')),
+            KeyResolution::Pending
+        );
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('r')),
+            KeyResolution::Action(Action::Refresh)
+        );
+    }
+
+    #[test]
+    fn a_key_breaking_a_sequence_starts_over() {
+        let mut keymap = keymap(&[(Action::Refresh, &["g r"])]);
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('g')),
+            KeyResolution::Pending
+        );
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('j')),
+            KeyResolution::Action(Action::Down)
+        );
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('g')),
+            KeyResolution::Pending
+        );
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('g')),
+            KeyResolution::Pending
+        );
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('r')),
+            KeyResolution::Action(Action::Refresh)
+        );
+    }
+
+    #[test]
+    fn resetting_drops_a_pending_sequence() {
+        let mut keymap = keymap(&[(Action::Refresh, &["g r"])]);
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('g')),
+            KeyResolution::Pending
+        );
+        keymap.reset_pending();
+        assert_eq!(
+            keymap.resolve(Scope::Stubs, &press('r')),
+            KeyResolution::Unbound
+        );
+    }
+}
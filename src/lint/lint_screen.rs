@@ -0,0 +1,155 @@
+use crate::keymap::action::{Action, Scope};
+use crate::lint::model::LintMsg;
+use crate::lint::rules::Severity;
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::ui;
+use async_trait::async_trait;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::Style;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct LintScreen {
+    sender: Sender<Message>,
+}
+
+impl LintScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        LintScreen { sender }
+    }
+
+    fn get_stub_details(&self, app: &ApplicationModel) -> String {
+        app.lint_model
+            .findings
+            .get(app.lint_model.selected_finding_index)
+            .and_then(|finding| app.stub_model.stubs.get(finding.stub_index))
+            .map(|stub| {
+                serde_json::to_string_pretty(stub).unwrap_or_else(|_| format!("{:#?}", stub))
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for LintScreen {
+    fn scope(&self) -> Scope {
+        Scope::Lint
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.lint_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock Inspector - Lint Report", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(main_layout[1]);
+
+        // Findings (left side)
+        let items: Vec<ListItem> = model
+            .findings
+            .iter()
+            .enumerate()
+            .map(|(i, finding)| {
+                let stub = app.stub_model.stubs.get(finding.stub_index);
+                let target = stub
+                    .map(|s| {
+                        format!(
+                            "{} {}",
+                            s.request.method,
+                            s.request.display_url().unwrap_or("(any url)")
+                        )
+                    })
+                    .unwrap_or_default();
+                let (badge, color) = match finding.kind.severity() {
+                    Severity::Error => ("✘", app.theme.error),
+                    Severity::Warning => ("⚠", app.theme.warning),
+                };
+                let text = format!(
+                    "{} {} [{}] {}",
+                    badge,
+                    target,
+                    finding.kind.label(),
+                    finding.message
+                );
+                let style = if i == model.selected_finding_index {
+                    app.theme.selected()
+                } else {
+                    Style::default().fg(color)
+                };
+                ListItem::new(text).style(style)
+            })
+            .collect();
+        let findings = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Findings ({})", model.findings.len())),
+        );
+        frame.render_widget(findings, content_layout[0]);
+
+        // Stub of the selected finding (right side)
+        let details = Paragraph::new(self.get_stub_details(app))
+            .block(Block::default().borders(Borders::ALL).title("Stub"))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(details, content_layout[1]);
+
+        // Status
+        let errors = model
+            .findings
+            .iter()
+            .filter(|f| f.kind.severity() == Severity::Error)
+            .count();
+        let status = Paragraph::new(format!(
+            "{} errors, {} warnings across {} stubs",
+            errors,
+            model.findings.len() - errors,
+            app.stub_model.stubs.len()
+        ));
+        frame.render_widget(status, main_layout[2]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Lint, &[]);
+        ui::widgets::render_commands(frame, main_layout[3], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::GoToStub => {
+                self.sender.send(Message::Lint(LintMsg::GoToSelectedStub))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender.send(Message::Lint(LintMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Lint(LintMsg::SelectNext))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+pub mod lint_screen;
+pub mod model;
+pub mod rules;
@@ -0,0 +1,71 @@
+use crate::lint::rules::LintFinding;
+use crate::model::{GlobalMsg, Message, ModelTrait};
+use crate::stub::model::StubMsg;
+use async_trait::async_trait;
+use std::error::Error;
+use tokio::sync::broadcast::Sender;
+
+pub struct LintModel {
+    pub event_sender: Sender<Message>,
+    pub findings: Vec<LintFinding>,
+    pub selected_finding_index: usize,
+}
+
+#[async_trait]
+impl ModelTrait<LintMsg, LintCommand> for LintModel {
+    async fn apply_event(&mut self, event: LintMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            LintMsg::SelectNext => {
+                if !self.findings.is_empty() {
+                    self.selected_finding_index =
+                        (self.selected_finding_index + 1).min(self.findings.len() - 1);
+                }
+                Ok(())
+            }
+            LintMsg::SelectPrevious => {
+                self.selected_finding_index = self.selected_finding_index.saturating_sub(1);
+                Ok(())
+            }
+            LintMsg::GoToSelectedStub => {
+                if let Some(finding) = self.findings.get(self.selected_finding_index) {
+                    self.event_sender
+                        .send(Message::Stub(StubMsg::Select(finding.stub_index)))?;
+                    self.event_sender
+                        .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, _: LintCommand) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
+
+impl LintModel {
+    pub fn new(event_sender: Sender<Message>) -> Self {
+        Self {
+            event_sender,
+            findings: vec![],
+            selected_finding_index: 0,
+        }
+    }
+
+    pub fn reset(&mut self, findings: Vec<LintFinding>) {
+        self.selected_finding_index = self
+            .selected_finding_index
+            .min(findings.len().saturating_sub(1));
+        self.findings = findings;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum LintCommand {}
+
+#[derive(Clone, Debug)]
+pub enum LintMsg {
+    SelectNext,
+    SelectPrevious,
+    GoToSelectedStub,
+}
@@ -0,0 +1,439 @@
+use crate::wire_mock::client::{RequestPattern, StubMapping};
+use crate::wire_mock::matcher;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LintKind {
+    Duplicate,
+    Shadowed,
+    InvalidRegex,
+    UrlPatternWithQuery,
+    JsonWithoutContentType,
+    UnreachableScenarioState,
+}
+
+impl LintKind {
+    pub fn severity(&self) -> Severity {
+        match self {
+            LintKind::InvalidRegex | LintKind::Shadowed | LintKind::Duplicate => Severity::Error,
+            _ => Severity::Warning,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LintKind::Duplicate => "duplicate",
+            LintKind::Shadowed => "shadowed",
+            LintKind::InvalidRegex => "invalid regex",
+            LintKind::UrlPatternWithQuery => "urlPattern vs query",
+            LintKind::JsonWithoutContentType => "no Content-Type",
+            LintKind::UnreachableScenarioState => "unreachable state",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LintFinding {
+    pub stub_index: usize,
+    pub kind: LintKind,
+    pub message: String,
+}
+
+/// Runs every lint rule over the stubs, most severe findings first.
+pub fn lint_stubs(stubs: &[StubMapping]) -> Vec<LintFinding> {
+    let mut findings = vec![];
+    lint_overlaps(stubs, &mut findings);
+    for (index, stub) in stubs.iter().enumerate() {
+        lint_regexes(index, stub, &mut findings);
+        lint_url_pattern(index, stub, &mut findings);
+        lint_content_type(index, stub, &mut findings);
+    }
+    lint_scenarios(stubs, &mut findings);
+    findings.sort_by_key(|f| (std::cmp::Reverse(f.kind.severity()), f.stub_index));
+    findings
+}
+
+fn short_id(stub: &StubMapping) -> &str {
+    stub.id.get(..8).unwrap_or(&stub.id)
+}
+
+/// Flags stubs that can never be served because a stub tried before them
+/// matches every request they would.
+fn lint_overlaps(stubs: &[StubMapping], findings: &mut Vec<LintFinding>) {
+    let order = matcher::match_order(stubs);
+    for (position, &index) in order.iter().enumerate() {
+        let stub = &stubs[index];
+        let Some(&earlier) = order[..position]
+            .iter()
+            .find(|&&earlier| covers(&stubs[earlier], stub))
+        else {
+            continue;
+        };
+        let (kind, message) = if stubs[earlier].same_definition(stub) {
+            (
+                LintKind::Duplicate,
+                format!("Exact duplicate of stub {}", short_id(&stubs[earlier])),
+            )
+        } else {
+            (
+                LintKind::Shadowed,
+                format!(
+                    "Never served: stub {} matches first",
+                    short_id(&stubs[earlier])
+                ),
+            )
+        };
+        findings.push(LintFinding {
+            stub_index: index,
+            kind,
+            message,
+        });
+    }
+}
+
+/// Whether `general` matches at least every request `specific` matches.
+/// Only provable cases count, so this errs on the side of no finding.
+fn covers(general: &StubMapping, specific: &StubMapping) -> bool {
+    let (g, s) = (&general.request, &specific.request);
+    if g.method != "ANY" && g.method != s.method {
+        return false;
+    }
+    if !url_covers(g, s) {
+        return false;
+    }
+    // Every extra matcher of the general stub must also constrain the specific one
+    let matchers_covered = g
+        .extra
+        .iter()
+        .all(|(key, value)| match (value, s.extra.get(key)) {
+            (Value::Object(g_map), Some(Value::Object(s_map))) => {
+                g_map.iter().all(|(name, p)| s_map.get(name) == Some(p))
+            }
+            (Value::Array(g_items), Some(Value::Array(s_items))) => {
+                g_items.iter().all(|p| s_items.contains(p))
+            }
+            (value, other) => Some(value) == other,
+        });
+    let scenario = |stub: &StubMapping| {
+        (
+            stub.scenario_name.clone(),
+            stub.extra.get("requiredScenarioState").cloned(),
+        )
+    };
+    let scenario_covered = general.scenario_name.is_none()
+        || general.extra.get("requiredScenarioState").is_none()
+        || scenario(general) == scenario(specific);
+    matchers_covered && scenario_covered
+}
+
+fn url_covers(g: &RequestPattern, s: &RequestPattern) -> bool {
+    let regex_matches = |pattern: &str, value: &str| {
+        matcher::compile_full_match(pattern).is_ok_and(|r| r.is_match(value))
+    };
+    let s_path = s
+        .url
+        .as_deref()
+        .map(|url| url.split_once('?').map_or(url, |(path, _)| path));
+    if let Some(url) = &g.url {
+        return s.url.as_ref() == Some(url);
+    }
+    if let Some(url_path) = &g.url_path {
+        return s.url_path.as_ref() == Some(url_path) || s_path == Some(url_path);
+    }
+    if let Some(pattern) = &g.url_pattern {
+        return s.url_pattern.as_ref() == Some(pattern)
+            || s.url
+                .as_deref()
+                .is_some_and(|url| regex_matches(pattern, url));
+    }
+    if let Some(pattern) = &g.url_path_pattern {
+        return s.url_path_pattern.as_ref() == Some(pattern)
+            || s.url_path
+                .as_deref()
+                .or(s_path)
+                .is_some_and(|path| regex_matches(pattern, path));
+    }
+    // No URL matcher at all matches any URL
+    match g.extra.get("urlPathTemplate") {
+        Some(template) => s.extra.get("urlPathTemplate") == Some(template),
+        None => true,
+    }
+}
+
+fn lint_regexes(index: usize, stub: &StubMapping, findings: &mut Vec<LintFinding>) {
+    let mut regexes: Vec<(String, &str)> = vec![];
+    if let Some(pattern) = &stub.request.url_pattern {
+        regexes.push(("urlPattern".to_string(), pattern));
+    }
+    if let Some(pattern) = &stub.request.url_path_pattern {
+        regexes.push(("urlPathPattern".to_string(), pattern));
+    }
+    for (key, value) in &stub.request.extra {
+        collect_matcher_regexes(key, value, &mut regexes);
+    }
+    for (location, pattern) in regexes {
+        if let Err(err) = matcher::compile_full_match(pattern) {
+            let reason = err.to_string();
+            findings.push(LintFinding {
+                stub_index: index,
+                kind: LintKind::InvalidRegex,
+                message: format!(
+                    "{} regex does not compile: {}",
+                    location,
+                    reason.lines().last().unwrap_or(&reason)
+                ),
+            });
+        }
+    }
+}
+
+fn collect_matcher_regexes<'a>(
+    location: &str,
+    value: &'a Value,
+    regexes: &mut Vec<(String, &'a str)>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, nested) in map {
+                match (key.as_str(), nested) {
+                    ("matches" | "doesNotMatch", Value::String(pattern)) => {
+                        regexes.push((location.to_string(), pattern));
+                    }
+                    _ => collect_matcher_regexes(&format!("{}.{}", location, key), nested, regexes),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_matcher_regexes(location, item, regexes);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `urlPattern` must match the query string too, so a pattern written for the
+/// path alone stops matching as soon as a client adds a query parameter.
+fn lint_url_pattern(index: usize, stub: &StubMapping, findings: &mut Vec<LintFinding>) {
+    let Some(pattern) = &stub.request.url_pattern else {
+        return;
+    };
+    let message = if stub.request.extra.contains_key("queryParameters") {
+        "urlPattern is combined with queryParameters; use urlPathPattern"
+    } else if !pattern.contains("\\?") && !pattern.ends_with(".*") {
+        "urlPattern fails on any query string; did you mean urlPathPattern?"
+    } else {
+        return;
+    };
+    findings.push(LintFinding {
+        stub_index: index,
+        kind: LintKind::UrlPatternWithQuery,
+        message: message.to_string(),
+    });
+}
+
+fn lint_content_type(index: usize, stub: &StubMapping, findings: &mut Vec<LintFinding>) {
+    let response = &stub.response;
+    let is_json = response.extra.contains_key("jsonBody")
+        || response
+            .body
+            .as_deref()
+            .and_then(|body| serde_json::from_str::<Value>(body).ok())
+            .is_some_and(|value| value.is_object() || value.is_array());
+    let has_content_type = response.headers.as_ref().is_some_and(|headers| {
+        headers
+            .keys()
+            .any(|name| name.eq_ignore_ascii_case("content-type"))
+    });
+    if is_json && !has_content_type {
+        findings.push(LintFinding {
+            stub_index: index,
+            kind: LintKind::JsonWithoutContentType,
+            message: "JSON body is served without a Content-Type header".to_string(),
+        });
+    }
+}
+
+/// Walks each scenario from its start state and flags stubs that require a
+/// state no stub ever moves the scenario into.
+fn lint_scenarios(stubs: &[StubMapping], findings: &mut Vec<LintFinding>) {
+    let state = |stub: &StubMapping, key: &str| {
+        stub.extra
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    let mut scenarios: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, stub) in stubs.iter().enumerate() {
+        if let Some(name) = &stub.scenario_name {
+            scenarios.entry(name).or_default().push(index);
+        }
+    }
+
+    for (name, members) in scenarios {
+        let mut reachable = HashSet::from([matcher::STARTED_STATE.to_string()]);
+        loop {
+            let before = reachable.len();
+            for &index in &members {
+                let stub = &stubs[index];
+                let active = state(stub, "requiredScenarioState")
+                    .is_none_or(|required| reachable.contains(&required));
+                if active && let Some(next) = state(stub, "newScenarioState") {
+                    reachable.insert(next);
+                }
+            }
+            if reachable.len() == before {
+                break;
+            }
+        }
+        for &index in &members {
+            if let Some(required) = state(&stubs[index], "requiredScenarioState")
+                && !reachable.contains(&required)
+            {
+                findings.push(LintFinding {
+                    stub_index: index,
+                    kind: LintKind::UnreachableScenarioState,
+                    message: format!("Scenario {} never reaches state {}", name, required),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn stub(mapping: Value) -> StubMapping {
+        serde_json::from_value(mapping).unwrap()
+    }
+
+    fn get(id: &str, request: Value) -> StubMapping {
+        let mut request = request;
+        request["method"] = json!("GET");
+        stub(json!({"id": id, "request": request, "response": {"status": 200}}))
+    }
+
+    fn kinds(stubs: &[StubMapping]) -> Vec<(usize, LintKind)> {
+        lint_stubs(stubs)
+            .into_iter()
+            .map(|f| (f.stub_index, f.kind))
+            .collect()
+    }
+
+    #[test]
+    fn flags_exact_duplicates() {
+        let stubs = [
+            get("first", json!({"urlPath": "/a"})),
+            get("second", json!({"urlPath": "/a"})),
+        ];
+        assert_eq!(kinds(&stubs), [(1, LintKind::Duplicate)]);
+    }
+
+    #[test]
+    fn flags_stubs_shadowed_by_a_more_general_one() {
+        let stubs = [
+            get("general", json!({"urlPath": "/a"})),
+            get(
+                "specific",
+                json!({"urlPath": "/a", "headers": {"X-Id": {"equalTo": "1"}}}),
+            ),
+        ];
+        assert_eq!(kinds(&stubs), [(1, LintKind::Shadowed)]);
+    }
+
+    #[test]
+    fn a_more_specific_stub_tried_first_shadows_nothing() {
+        let stubs = [
+            get(
+                "specific",
+                json!({"urlPath": "/a", "headers": {"X-Id": {"equalTo": "1"}}}),
+            ),
+            get("general", json!({"urlPath": "/a"})),
+            get("other", json!({"urlPath": "/b"})),
+        ];
+        assert!(kinds(&stubs).is_empty());
+    }
+
+    #[test]
+    fn flags_regexes_that_do_not_compile() {
+        let stubs = [
+            get("path", json!({"urlPathPattern": "/a/(["})),
+            get(
+                "header",
+                json!({"urlPath": "/b", "headers": {"X-Id": {"matches": "[0-9"}}}),
+            ),
+            get("valid", json!({"urlPathPattern": "/c/[0-9]+"})),
+        ];
+        let findings = lint_stubs(&stubs);
+        assert_eq!(
+            findings
+                .iter()
+                .map(|f| (f.stub_index, f.kind))
+                .collect::<Vec<_>>(),
+            [(0, LintKind::InvalidRegex), (1, LintKind::InvalidRegex)]
+        );
+        assert!(findings[0].message.starts_with("urlPathPattern regex"));
+        assert!(findings[1].message.starts_with("headers.X-Id regex"));
+    }
+
+    #[test]
+    fn flags_url_pattern_with_query_parameters_or_without_a_query() {
+        let stubs = [
+            get(
+                "with query",
+                json!({"urlPattern": "/a.*", "queryParameters": {"q": {"equalTo": "1"}}}),
+            ),
+            get("path only", json!({"urlPattern": "/b/[0-9]+"})),
+            get("any query", json!({"urlPattern": "/c/[0-9]+\\?.*"})),
+        ];
+        let findings: Vec<LintFinding> = lint_stubs(&stubs)
+            .into_iter()
+            .filter(|f| f.kind == LintKind::UrlPatternWithQuery)
+            .collect();
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].stub_index, 0);
+        assert!(findings[0]
+            .message
+            .contains("combined with queryParameters"));
+        assert_eq!(findings[1].stub_index, 1);
+    }
+
+    #[test]
+    fn flags_scenario_states_no_stub_moves_into() {
+        let scenario = |id: &str, path: &str, required: Option<&str>, next: Option<&str>| {
+            let mut mapping = json!({
+                "id": id,
+                "scenarioName": "checkout",
+                "request": {"method": "GET", "urlPath": path},
+                "response": {"status": 200}
+            });
+            if let Some(required) = required {
+                mapping["requiredScenarioState"] = json!(required);
+            }
+            if let Some(next) = next {
+                mapping["newScenarioState"] = json!(next);
+            }
+            stub(mapping)
+        };
+        let stubs = [
+            scenario("start", "/cart", Some("Started"), Some("Filled")),
+            scenario("pay", "/pay", Some("Filled"), Some("Paid")),
+            scenario("ship", "/ship", Some("Paid"), None),
+            scenario("refund", "/refund", Some("Refunded"), None),
+        ];
+        let findings = lint_stubs(&stubs);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].stub_index, 3);
+        assert_eq!(findings[0].kind, LintKind::UnreachableScenarioState);
+        assert!(findings[0].message.contains("Refunded"));
+    }
+}
@@ -1,39 +1,105 @@
+use crate::cli::Cli;
+use crate::keymap::action::{Action, Scope};
+use crate::keymap::{KeyResolution, Keymap};
 use crate::model::{Command, GlobalMsg, Message, ModelTrait};
-use crate::server::model::ServerMsg;
-use crossterm::event::EventStream;
+use crate::server::model::{load_configuration, ServerMsg};
+use crate::ui::theme::Theme;
+use clap::Parser;
+use crossterm::event::{Event, EventStream};
 use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use futures::StreamExt;
 use model::ApplicationModel;
+use ratatui::layout::Rect;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::error::Error;
 use std::io;
 use std::time::Duration;
 use tokio::time;
+use tokio::time::MissedTickBehavior;
 
+mod admin;
+mod alerts;
+mod cli;
+mod compare;
+mod coverage;
+mod dashboard;
+mod har;
+mod help;
+mod import;
+mod journal;
+mod keymap;
+mod lint;
 mod model;
+mod openapi;
+mod palette;
 mod server;
 mod stub;
+mod sync;
+mod tester;
+mod transfer;
 mod ui;
 mod wire_mock;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    let configuration = match load_configuration(cli.config.as_deref()) {
+        Ok(configuration) => configuration,
+        Err(err) => exit_with_error(&err),
+    };
+    let startup_server = match cli.startup_server(&configuration) {
+        Ok(server) => server,
+        Err(err) => exit_with_error(&err),
+    };
+    if let Some(command) = cli.command {
+        if let Err(err) = cli::commands::run(
+            command,
+            cli.output,
+            &configuration,
+            startup_server,
+            cli.read_only,
+        ) {
+            exit_with_error(err.as_ref());
+        }
+        return Ok(());
+    }
+
+    // Bad key bindings and themes are reported before the terminal is taken over
+    let keymap = match Keymap::new(&configuration.keys) {
+        Ok(keymap) => keymap,
+        Err(err) => exit_with_error(&err),
+    };
+    let theme = match Theme::resolve(configuration.theme.as_deref(), &configuration.themes) {
+        Ok(theme) => theme,
+        Err(err) => exit_with_error(&err),
+    };
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     // Create app state
     let mut app = ApplicationModel::new()?;
+    app.keymap = keymap;
+    app.theme = theme;
+    app.server_model.config_path = cli.config;
+    app.server_model.startup_server = startup_server;
+    app.server_model.read_only_servers.set_all(cli.read_only);
     let res = run_app(&mut terminal, &mut app).await;
 
     // Restore terminal
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
     terminal.show_cursor()?;
     if let Err(err) = res {
         println!("Error: {:?}", err);
@@ -42,53 +108,142 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+/// Reports a command-line error readably, without entering the UI.
+fn exit_with_error(err: &dyn Error) -> ! {
+    eprintln!("Error: {}", err);
+    std::process::exit(1);
+}
+
+/// Shortest time between two frames, capping redraws at about 60 per second.
+const FRAME_INTERVAL: Duration = Duration::from_millis(16);
+/// How often spinners and other animations move on.
+const ANIMATION_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Runs the UI until the user quits. The screen is redrawn only after
+/// something changed: a message or command was handled, the terminal was
+/// resized or an animation ticked. Keys and mouse gestures change the state
+/// through messages, so mouse moves and unbound keys draw nothing.
 async fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app: &mut ApplicationModel,
 ) -> Result<(), Box<dyn Error>> {
     let mut reader = EventStream::new();
     send_initial_events(app).await?;
+    let mut frame_timer = time::interval(FRAME_INTERVAL);
+    frame_timer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut animation_timer = time::interval(ANIMATION_INTERVAL);
+    animation_timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let mut needs_redraw = true;
     loop {
+        let animating = app.is_animating();
         tokio::select! {
 
             event_option = app.event_channel.1.recv() => {
+                needs_redraw = true;
                  if let Ok(msg) = event_option {
                     let _ = match msg {
                         Message::Global(ev) => app.apply_event(ev).await,
                         Message::Server(ev) => app.server_model.apply_event(ev).await,
                         Message::Stub(ev) => app.stub_model.apply_event(ev).await,
+                        Message::Import(ev) => app.import_model.apply_event(ev).await,
+                        Message::Compare(ev) => app.compare_model.apply_event(ev).await,
+                        Message::Transfer(ev) => app.transfer_model.apply_event(ev).await,
+                        Message::Sync(ev) => app.sync_model.apply_event(ev).await,
+                        Message::OpenApi(ev) => app.openapi_model.apply_event(ev).await,
+                        Message::Har(ev) => app.har_model.apply_event(ev).await,
+                        Message::Journal(ev) => app.journal_model.apply_event(ev).await,
+                        Message::Tester(ev) => app.tester_model.apply_event(ev).await,
+                        Message::Lint(ev) => app.lint_model.apply_event(ev).await,
+                        Message::Dashboard(ev) => app.dashboard_model.apply_event(ev).await,
+                        Message::Alert(ev) => app.alert_model.apply_event(ev).await,
+                        Message::Admin(ev) => app.admin_model.apply_event(ev).await,
+                        Message::Help(ev) => app.help_model.apply_event(ev).await,
+                        Message::Palette(ev) => app.palette_model.apply_event(ev).await,
                         Message::QuitRequested => return Ok(()),
                     };
                 }
             }
 
             command_option = app.command_channel.1.recv() => {
+                needs_redraw = true;
                 if let Ok(msg) = command_option {
                     match msg{
                             Command::Global(ev) => app.handle_command(ev).await?,
                             Command::Server(ev) => app.server_model.handle_command(ev).await?,
                             Command::Stub(ev) => app.stub_model.handle_command(ev).await?,
+                            Command::Import(ev) => app.import_model.handle_command(ev).await?,
+                            Command::Compare(ev) => app.compare_model.handle_command(ev).await?,
+                            Command::Transfer(ev) => app.transfer_model.handle_command(ev).await?,
+                            Command::Sync(ev) => app.sync_model.handle_command(ev).await?,
+                            Command::OpenApi(ev) => app.openapi_model.handle_command(ev).await?,
+                            Command::Har(ev) => app.har_model.handle_command(ev).await?,
+                            Command::Journal(ev) => app.journal_model.handle_command(ev).await?,
+                            Command::Tester(ev) => app.tester_model.handle_command(ev).await?,
+                            Command::Lint(ev) => app.lint_model.handle_command(ev).await?,
+                            Command::Dashboard(ev) => app.dashboard_model.handle_command(ev).await?,
+                            Command::Alert(ev) => app.alert_model.handle_command(ev).await?,
+                            Command::Admin(ev) => app.admin_model.handle_command(ev).await?,
+                            Command::Help(ev) => app.help_model.handle_command(ev).await?,
+                            Command::Palette(ev) => app.palette_model.handle_command(ev).await?,
                     }
                 }
             }
 
             maybe_event = reader.next() => {
-                    match maybe_event{
-                        Some(Ok(event))  => {
-                            match &app.screen{
-                                Some(screen) => screen.handle_key_event(&event).await?,
-                                None => {}
+                    // The terminal may have dropped what was drawn before
+                    if let Some(Ok(Event::Resize(..) | Event::FocusGained)) = &maybe_event {
+                        needs_redraw = true;
+                    }
+                    let gesture = match &maybe_event {
+                        Some(Ok(Event::Mouse(mouse))) => app.mouse.gesture(mouse),
+                        _ => None,
+                    };
+                    // An open overlay takes the keys from the screen below it
+                    let screen = app.overlay.as_ref().or(app.screen.as_ref());
+                    if let (Some(gesture), Some(screen)) = (gesture, screen) {
+                        let size = terminal.size()?;
+                        let area = Rect::new(0, 0, size.width, size.height);
+                        screen.handle_mouse(app, area, gesture)?;
+                    } else if let (Some(Ok(event)), Some(screen)) = (maybe_event, screen) {
+                        let scope = screen.scope();
+                        let resolution = match &event {
+                            Event::Key(key) => app.keymap.resolve(scope, key),
+                            _ => KeyResolution::Unbound,
+                        };
+                        match resolution {
+                            KeyResolution::Action(action) if !screen.takes_action(app, action) => {}
+                            KeyResolution::Action(Action::Help) if scope != Scope::Help => {
+                                app.event_channel
+                                    .0
+                                    .send(Message::Global(GlobalMsg::OpenHelp(scope)))?;
+                            }
+                            KeyResolution::Action(Action::CommandPalette)
+                                if scope != Scope::Palette =>
+                            {
+                                app.event_channel
+                                    .0
+                                    .send(Message::Global(GlobalMsg::OpenCommandPalette))?;
                             }
+                            KeyResolution::Action(action) => screen.handle_action(action).await?,
+                            KeyResolution::Pending => {}
+                            KeyResolution::Unbound => screen.handle_key_event(&event).await?,
                         }
-                        _ => ()
                     }
                 }
 
-            _ = time::sleep(Duration::from_millis(70)) => {
-                match &app.screen {
-                    Some(screen) =>   {terminal.draw(|f| screen.draw(app, f))?;
-                    },
-                _ => {}
+            _ = animation_timer.tick(), if animating => {
+                needs_redraw = true;
+            }
+
+            _ = frame_timer.tick(), if needs_redraw => {
+                needs_redraw = false;
+                if let Some(screen) = &app.screen {
+                    terminal.draw(|f| {
+                        screen.draw(app, f);
+                        if let Some(overlay) = &app.overlay {
+                            overlay.draw(app, f);
+                        }
+                    })?;
                 }
             }
         }
@@ -99,8 +254,11 @@ async fn send_initial_events(app: &mut ApplicationModel) -> Result<(), Box<dyn E
     app.event_channel
         .0
         .send(Message::Server(ServerMsg::LoadConfigurationRequested))?;
-    app.event_channel
-        .0
-        .send(Message::Global(GlobalMsg::SwitchToServerSelectionScreen))?;
+    // A server given on the command line opens once the configuration is loaded
+    if app.server_model.startup_server.is_none() {
+        app.event_channel
+            .0
+            .send(Message::Global(GlobalMsg::SwitchToServerSelectionScreen))?;
+    }
     Ok(())
 }
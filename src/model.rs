@@ -1,11 +1,45 @@
-use crate::server::model::{ServerCommand, ServerModel, ServerMsg};
+use crate::admin::admin_screen::AdminScreen;
+use crate::admin::model::{AdminCommand, AdminModel, AdminMsg};
+use crate::alerts::model::{AlertCommand, AlertModel, AlertMsg};
+use crate::compare::compare_screen::CompareScreen;
+use crate::compare::model::{CompareCommand, CompareModel, CompareMsg};
+use crate::coverage::coverage_screen::CoverageScreen;
+use crate::dashboard::dashboard_screen::DashboardScreen;
+use crate::dashboard::model::{DashboardCommand, DashboardModel, DashboardMsg};
+use crate::har::har_screen::HarScreen;
+use crate::har::model::{HarCommand, HarModel, HarMsg};
+use crate::help::help_overlay::HelpOverlay;
+use crate::help::model::{HelpCommand, HelpModel, HelpMsg};
+use crate::import::import_screen::ImportScreen;
+use crate::import::model::{ImportCommand, ImportModel, ImportMsg};
+use crate::journal::journal_screen::JournalScreen;
+use crate::journal::model::{JournalCommand, JournalModel, JournalMsg};
+use crate::journal::stub_draft_screen::StubDraftScreen;
+use crate::keymap::action::{Action, Scope};
+use crate::keymap::Keymap;
+use crate::lint::lint_screen::LintScreen;
+use crate::lint::model::{LintCommand, LintModel, LintMsg};
+use crate::openapi::model::{OpenApiCommand, OpenApiModel, OpenApiMsg};
+use crate::openapi::openapi_screen::OpenApiScreen;
+use crate::palette::model::{PaletteCommand, PaletteModel, PaletteMsg};
+use crate::palette::palette_overlay::PaletteOverlay;
+use crate::server::model::{ReadOnlyServers, ServerCommand, ServerModel, ServerMsg};
 use crate::server::server_edit_screen::ServerEditScreen;
 use crate::server::server_selection_screen::ServerSelectionScreen;
 use crate::stub;
 use crate::stub::model::StubCommand;
 use crate::stub::stub_screen::StubScreen;
+use crate::sync::model::{SyncCommand, SyncModel, SyncMsg};
+use crate::sync::sync_screen::SyncScreen;
+use crate::tester::model::{TesterCommand, TesterModel, TesterMsg};
+use crate::tester::tester_screen::TesterScreen;
+use crate::transfer::model::{TransferCommand, TransferMode, TransferModel, TransferMsg};
+use crate::transfer::transfer_screen::TransferScreen;
+use crate::ui::mouse::{MouseGesture, MouseTracker};
+use crate::ui::theme::Theme;
 use async_trait::async_trait;
 use crossterm::event::Event;
+use ratatui::layout::Rect;
 use ratatui::Frame;
 use std::error::Error;
 use stub::model::StubModel;
@@ -15,8 +49,27 @@ use tokio::sync::broadcast::{Receiver, Sender};
 
 pub struct ApplicationModel {
     pub screen: Option<Box<dyn ScreenTrait + Send>>,
+    /// Modal drawn over the screen; it takes the keys while open.
+    pub overlay: Option<Box<dyn ScreenTrait + Send>>,
     pub server_model: ServerModel,
     pub stub_model: StubModel,
+    pub import_model: ImportModel,
+    pub compare_model: CompareModel,
+    pub transfer_model: TransferModel,
+    pub sync_model: SyncModel,
+    pub openapi_model: OpenApiModel,
+    pub har_model: HarModel,
+    pub journal_model: JournalModel,
+    pub tester_model: TesterModel,
+    pub lint_model: LintModel,
+    pub dashboard_model: DashboardModel,
+    pub alert_model: AlertModel,
+    pub admin_model: AdminModel,
+    pub help_model: HelpModel,
+    pub palette_model: PaletteModel,
+    pub keymap: Keymap,
+    pub theme: Theme,
+    pub mouse: MouseTracker,
     pub event_channel: (Sender<Message>, Receiver<Message>),
     pub command_channel: (Sender<Command>, Receiver<Command>),
 }
@@ -28,6 +81,18 @@ impl ModelTrait<GlobalMsg, GlobalCommand> for ApplicationModel {
             GlobalMsg::SwitchToStubScreen => {
                 let selected_server = self.server_model.current_selected_server();
                 self.stub_model.selected_server_url = selected_server.cloned();
+                self.stub_model.status_message =
+                    self.server_model.selected_server_down().map(|reason| {
+                        format!(
+                            "Warning: server was unreachable at the last check ({})",
+                            reason
+                        )
+                    });
+                self.event_channel
+                    .0
+                    .send(Message::Alert(AlertMsg::WatchServer(
+                        self.stub_model.selected_server_url.clone(),
+                    )))?;
                 self.switch_to_main_screen();
                 Ok(())
             }
@@ -39,6 +104,142 @@ impl ModelTrait<GlobalMsg, GlobalCommand> for ApplicationModel {
                 self.switch_to_server_edit_screen();
                 Ok(())
             }
+            GlobalMsg::SwitchToImportScreen => {
+                if self.refuse_in_read_only("importing stubs") {
+                    return Ok(());
+                }
+                self.import_model.selected_server_url = self.stub_model.selected_server_url.clone();
+                self.switch_to_import_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToCompareScreen => {
+                self.compare_model.reset(
+                    self.server_model.server_list.clone(),
+                    self.server_model.current_selected_server_index.unwrap_or(0),
+                );
+                self.switch_to_compare_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToTransferScreen(mode) => {
+                if mode == TransferMode::Move && self.refuse_in_read_only("moving stubs") {
+                    return Ok(());
+                }
+                self.transfer_model.reset(
+                    self.stub_model.selected_server_url.clone(),
+                    self.server_model.server_list.clone(),
+                    self.stub_model.marked_or_selected_stubs(),
+                    mode,
+                );
+                self.switch_to_transfer_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToSyncScreen => {
+                if self.refuse_in_read_only("syncing a directory") {
+                    return Ok(());
+                }
+                if !self.sync_model.is_watching() {
+                    self.sync_model.server_url = self.stub_model.selected_server_url.clone();
+                }
+                self.switch_to_sync_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToOpenApiScreen => {
+                if self.refuse_in_read_only("generating stubs from OpenAPI") {
+                    return Ok(());
+                }
+                self.openapi_model.selected_server_url =
+                    self.stub_model.selected_server_url.clone();
+                self.switch_to_openapi_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToHarScreen => {
+                if self.refuse_in_read_only("importing HAR recordings") {
+                    return Ok(());
+                }
+                self.har_model.selected_server_url = self.stub_model.selected_server_url.clone();
+                self.switch_to_har_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToJournalScreen => {
+                self.journal_model.selected_server_url =
+                    self.stub_model.selected_server_url.clone();
+                self.event_channel
+                    .0
+                    .send(Message::Journal(JournalMsg::ReadRequestsRequested))?;
+                self.event_channel
+                    .0
+                    .send(Message::Alert(AlertMsg::Acknowledge))?;
+                self.switch_to_journal_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToStubDraftScreen => {
+                self.switch_to_stub_draft_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToTesterScreen => {
+                self.tester_model.selected_server_url = self.stub_model.selected_server_url.clone();
+                self.tester_model.stubs = self.stub_model.stubs.clone();
+                self.switch_to_tester_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToCoverageScreen => {
+                self.event_channel
+                    .0
+                    .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+                self.event_channel
+                    .0
+                    .send(Message::Stub(StubMsg::ReadHitsRequested))?;
+                self.switch_to_coverage_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToDashboardScreen => {
+                self.dashboard_model
+                    .reset(self.stub_model.selected_server_url.clone());
+                self.event_channel
+                    .0
+                    .send(Message::Dashboard(DashboardMsg::StartPolling))?;
+                self.switch_to_dashboard_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToAdminScreen => {
+                if self.refuse_in_read_only("housekeeping") {
+                    return Ok(());
+                }
+                self.admin_model.selected_server_url = self.stub_model.selected_server_url.clone();
+                self.admin_model.pending_confirmation = None;
+                self.switch_to_admin_screen();
+                Ok(())
+            }
+            GlobalMsg::SwitchToLintScreen => {
+                self.lint_model.reset(self.stub_model.findings.clone());
+                self.switch_to_lint_screen();
+                Ok(())
+            }
+            GlobalMsg::OpenHelp(scope) => {
+                self.help_model.reset(scope);
+                self.overlay = Some(Box::new(HelpOverlay::new(self.event_channel.0.clone())));
+                self.keymap.reset_pending();
+                Ok(())
+            }
+            GlobalMsg::OpenCommandPalette => {
+                // Palette commands open the selected server's stubs first when
+                // they are not loaded, so they act on that server even from
+                // the server selection
+                let server = self.server_model.current_selected_server();
+                self.palette_model.reset(
+                    server.is_some(),
+                    server.is_some_and(|server| self.server_model.is_read_only(server)),
+                    server.is_some() && server == self.stub_model.selected_server_url.as_ref(),
+                );
+                self.overlay = Some(Box::new(PaletteOverlay::new(self.event_channel.0.clone())));
+                self.keymap.reset_pending();
+                Ok(())
+            }
+            GlobalMsg::CloseOverlay => {
+                self.overlay = None;
+                self.keymap.reset_pending();
+                Ok(())
+            }
         }
     }
 
@@ -51,56 +252,243 @@ impl ApplicationModel {
     pub fn new() -> Result<Self, Box<dyn Error>> {
         let event_channel = tokio::sync::broadcast::channel::<Message>(100);
         let command_channel = tokio::sync::broadcast::channel::<Command>(100);
+        let read_only_servers = ReadOnlyServers::default();
         let application_model = ApplicationModel {
             screen: None,
-            server_model: ServerModel::new(event_channel.0.clone(), command_channel.0.clone()),
-            stub_model: StubModel::new(event_channel.0.clone(), command_channel.0.clone()),
+            overlay: None,
+            server_model: ServerModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            stub_model: StubModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            import_model: ImportModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            compare_model: CompareModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            transfer_model: TransferModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            sync_model: SyncModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            openapi_model: OpenApiModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            har_model: HarModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            journal_model: JournalModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            tester_model: TesterModel::new(event_channel.0.clone(), command_channel.0.clone()),
+            lint_model: LintModel::new(event_channel.0.clone()),
+            dashboard_model: DashboardModel::new(event_channel.0.clone()),
+            alert_model: AlertModel::new(event_channel.0.clone()),
+            admin_model: AdminModel::new(
+                event_channel.0.clone(),
+                command_channel.0.clone(),
+                read_only_servers.clone(),
+            ),
+            help_model: HelpModel::new(),
+            palette_model: PaletteModel::new(event_channel.0.clone()),
+            keymap: Keymap::default(),
+            theme: Theme::default(),
+            mouse: MouseTracker::default(),
             event_channel,
             command_channel,
         };
         Ok(application_model)
     }
 
-    fn switch_to_main_screen(self: &mut Self) {
-        self.screen = Some(Box::new(StubScreen::new(self.event_channel.0.clone())));
+    /// Whether something on screen changes by itself, like a spinner or the
+    /// time since the last sync, and needs redrawing without any message.
+    pub fn is_animating(&self) -> bool {
+        self.server_model.is_probing() || self.sync_model.is_watching()
     }
 
-    fn switch_to_server_selection_screen(self: &mut Self) {
-        self.screen = Some(Box::new(ServerSelectionScreen::new(
+    /// Refuses to open a screen that changes the selected server when it is
+    /// read-only, telling the user why.
+    fn refuse_in_read_only(&mut self, action: &str) -> bool {
+        let read_only = self.stub_model.is_read_only();
+        if read_only {
+            self.stub_model.refuse_in_read_only(action);
+        }
+        read_only
+    }
+
+    /// Replaces the screen, telling the models whether theirs is shown.
+    fn show_screen(&mut self, screen: Box<dyn ScreenTrait + Send>) {
+        self.keymap.reset_pending();
+        self.admin_model.set_active(screen.scope() == Scope::Admin);
+        if screen.scope() != Scope::Dashboard {
+            self.dashboard_model.stop_polling();
+        }
+        self.screen = Some(screen);
+    }
+
+    fn switch_to_main_screen(&mut self) {
+        self.show_screen(Box::new(StubScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_server_selection_screen(&mut self) {
+        self.show_screen(Box::new(ServerSelectionScreen::new(
             self.event_channel.0.clone(),
         )));
     }
 
-    fn switch_to_server_edit_screen(self: &mut Self) {
-        self.screen = Some(Box::new(ServerEditScreen::new(
+    fn switch_to_server_edit_screen(&mut self) {
+        self.show_screen(Box::new(ServerEditScreen::new(
             self.event_channel.0.clone(),
         )));
     }
+
+    fn switch_to_import_screen(&mut self) {
+        self.show_screen(Box::new(ImportScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_compare_screen(&mut self) {
+        self.show_screen(Box::new(CompareScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_transfer_screen(&mut self) {
+        self.show_screen(Box::new(TransferScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_sync_screen(&mut self) {
+        self.show_screen(Box::new(SyncScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_openapi_screen(&mut self) {
+        self.show_screen(Box::new(OpenApiScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_har_screen(&mut self) {
+        self.show_screen(Box::new(HarScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_journal_screen(&mut self) {
+        self.show_screen(Box::new(JournalScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_stub_draft_screen(&mut self) {
+        self.show_screen(Box::new(StubDraftScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_tester_screen(&mut self) {
+        self.show_screen(Box::new(TesterScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_coverage_screen(&mut self) {
+        self.show_screen(Box::new(CoverageScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_dashboard_screen(&mut self) {
+        self.show_screen(Box::new(DashboardScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_admin_screen(&mut self) {
+        self.show_screen(Box::new(AdminScreen::new(self.event_channel.0.clone())));
+    }
+
+    fn switch_to_lint_screen(&mut self) {
+        self.show_screen(Box::new(LintScreen::new(self.event_channel.0.clone())));
+    }
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug)]
 pub enum Command {
     Server(ServerCommand),
     Stub(StubCommand),
+    Import(ImportCommand),
+    Compare(CompareCommand),
+    Transfer(TransferCommand),
+    Sync(SyncCommand),
+    OpenApi(OpenApiCommand),
+    Har(HarCommand),
+    Journal(JournalCommand),
+    Tester(TesterCommand),
+    #[allow(dead_code)] // the dashboard polls in the background, no commands yet
+    Dashboard(DashboardCommand),
+    #[allow(dead_code)] // alerts poll in the background, no commands yet
+    Alert(AlertCommand),
+    Admin(AdminCommand),
+    #[allow(dead_code)] // help is static, no commands yet
+    Help(HelpCommand),
+    #[allow(dead_code)] // the palette sends messages only
+    Palette(PaletteCommand),
+    #[allow(dead_code)] // linting runs locally, no commands yet
+    Lint(LintCommand),
+    #[allow(dead_code)] // no global commands yet
     Global(GlobalCommand),
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug)]
 pub enum Message {
     QuitRequested,
     Global(GlobalMsg),
     Server(ServerMsg),
     Stub(StubMsg),
+    Import(ImportMsg),
+    Compare(CompareMsg),
+    Transfer(TransferMsg),
+    Sync(SyncMsg),
+    OpenApi(OpenApiMsg),
+    Har(HarMsg),
+    Journal(JournalMsg),
+    Tester(TesterMsg),
+    Lint(LintMsg),
+    Dashboard(DashboardMsg),
+    Alert(AlertMsg),
+    Admin(AdminMsg),
+    Help(HelpMsg),
+    Palette(PaletteMsg),
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug)]
 pub enum GlobalMsg {
     SwitchToStubScreen,
     SwitchToServerSelectionScreen,
     SwitchToConnectionEditScreen,
+    SwitchToImportScreen,
+    SwitchToCompareScreen,
+    SwitchToTransferScreen(TransferMode),
+    SwitchToSyncScreen,
+    SwitchToOpenApiScreen,
+    SwitchToHarScreen,
+    SwitchToJournalScreen,
+    SwitchToStubDraftScreen,
+    SwitchToTesterScreen,
+    SwitchToLintScreen,
+    SwitchToCoverageScreen,
+    SwitchToDashboardScreen,
+    SwitchToAdminScreen,
+    OpenHelp(Scope),
+    OpenCommandPalette,
+    CloseOverlay,
 }
 
-#[derive(Clone,Debug)]
+#[derive(Clone, Debug)]
 pub enum GlobalCommand {}
 
 #[async_trait]
@@ -110,11 +498,33 @@ pub trait ModelTrait<E, C> {
 }
 
 #[async_trait]
-pub trait ScreenTrait {
+pub trait ScreenTrait: Sync {
+    /// Which key bindings apply while the screen is shown.
+    fn scope(&self) -> Scope;
     fn draw(&self, app: &ApplicationModel, f: &mut Frame);
-    async fn handle_key_event(&self, key_event: &Event) -> Result<(), Box<dyn std::error::Error>>;
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>>;
+    /// Whether the screen takes `action` as it is drawn now. A screen showing
+    /// a menu takes only the keys of the menu.
+    fn takes_action(&self, _app: &ApplicationModel, _action: Action) -> bool {
+        true
+    }
+    /// Receives the keys no binding uses, such as text typed into a field.
+    async fn handle_key_event(&self, _key_event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+    /// Receives mouse gestures over the screen drawn in `area`, hit testing
+    /// them against the layout `draw` uses.
+    fn handle_mouse(
+        &self,
+        _app: &ApplicationModel,
+        _area: Rect,
+        _gesture: MouseGesture,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
 }
 
+#[allow(dead_code)] // not raised anywhere yet
 #[derive(Error, Debug)]
 pub enum AppError {
     #[error("Global error occurred: {0}")]
@@ -124,6 +534,7 @@ pub enum AppError {
     Stub(#[from] stub::model::StubError),
 }
 
+#[allow(dead_code)] // not raised anywhere yet
 #[derive(Error, Debug)]
 pub enum GlobalError {
     #[error("User exit requested")]
@@ -0,0 +1,334 @@
+use crate::wire_mock::client::{RequestPattern, ResponseDefinition, WireMockStub};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+const METHODS: [&str; 8] = [
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+const MAX_EXAMPLE_DEPTH: usize = 8;
+
+/// A stub generated for one operation of the spec.
+#[derive(Clone, Debug)]
+pub struct GeneratedStub {
+    pub label: String,
+    pub stub: WireMockStub,
+}
+
+/// Parses an OpenAPI 3 document; YAML is a superset of JSON so both work.
+pub fn load_spec(path: &Path) -> Result<Value, Box<dyn Error>> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let spec: Value = serde_yaml::from_str(&content)
+        .map_err(|e| format!("Invalid spec {}: {}", path.display(), e))?;
+    match spec.get("openapi").and_then(Value::as_str) {
+        Some(version) if version.starts_with('3') => Ok(spec),
+        _ => Err(format!("{} is not an OpenAPI 3 document", path.display()).into()),
+    }
+}
+
+pub fn generate_stubs(spec: &Value) -> Vec<GeneratedStub> {
+    let base_path = base_path(spec);
+    let Some(paths) = spec.get("paths").and_then(Value::as_object) else {
+        return vec![];
+    };
+    let mut stubs = vec![];
+    for (path, item) in paths {
+        for method in METHODS {
+            let Some(operation) = item.get(method) else {
+                continue;
+            };
+            let label = match operation.get("operationId").and_then(Value::as_str) {
+                Some(id) => format!("{} {} ({})", method.to_uppercase(), path, id),
+                None => format!("{} {}", method.to_uppercase(), path),
+            };
+            stubs.push(GeneratedStub {
+                label,
+                stub: WireMockStub {
+                    request: RequestPattern {
+                        method: method.to_uppercase(),
+                        url: None,
+                        url_pattern: None,
+                        url_path: None,
+                        url_path_pattern: Some(path_pattern(&base_path, path)),
+                        extra: Map::new(),
+                    },
+                    response: response_definition(spec, operation),
+                },
+            });
+        }
+    }
+    stubs
+}
+
+/// Path part of the first server url, e.g. `/v1` for `https://host/v1`.
+fn base_path(spec: &Value) -> String {
+    let url = spec
+        .pointer("/servers/0/url")
+        .and_then(Value::as_str)
+        .unwrap_or("");
+    let path = match url.find("://") {
+        Some(i) => url[i + 3..]
+            .find('/')
+            .map(|j| &url[i + 3 + j..])
+            .unwrap_or(""),
+        None => url,
+    };
+    path.trim_end_matches('/').to_string()
+}
+
+/// Turns `/pets/{petId}` into the regex `/pets/[^/]+`.
+fn path_pattern(base_path: &str, path: &str) -> String {
+    let mut pattern = String::new();
+    let mut rest = format!("{}{}", base_path, path);
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+        pattern.push_str(&escape_regex(&rest[..start]));
+        pattern.push_str("[^/]+");
+        rest = rest[start + end + 1..].to_string();
+    }
+    pattern.push_str(&escape_regex(&rest));
+    pattern
+}
+
+fn escape_regex(text: &str) -> String {
+    text.chars()
+        .flat_map(|c| {
+            if "\\.+*?()|[]{}^$".contains(c) {
+                vec!['\\', c]
+            } else {
+                vec![c]
+            }
+        })
+        .collect()
+}
+
+/// Uses the lowest 2xx response, falling back to `default` and then to
+/// whatever response is documented first.
+fn response_definition(spec: &Value, operation: &Value) -> ResponseDefinition {
+    let responses = operation.get("responses").and_then(Value::as_object);
+    let chosen = responses.and_then(|responses| {
+        let mut codes: Vec<&String> = responses.keys().collect();
+        codes.sort();
+        codes
+            .iter()
+            .find(|code| code.starts_with('2'))
+            .or(codes.iter().find(|code| code.as_str() == "default"))
+            .or(codes.first())
+            .map(|code| (code.to_string(), &responses[code.as_str()]))
+    });
+
+    let Some((code, response)) = chosen else {
+        return ResponseDefinition {
+            status: 200,
+            body: None,
+            headers: None,
+            extra: Map::new(),
+        };
+    };
+    let status = code.replace('X', "0").parse().unwrap_or(200);
+    let response = resolve(spec, response);
+    let content = response.get("content").and_then(Value::as_object);
+    let media = content.and_then(|content| {
+        content
+            .iter()
+            .find(|(media_type, _)| media_type.contains("json"))
+            .or(content.iter().next())
+    });
+
+    let (body, headers) = match media {
+        Some((media_type, media)) => {
+            let body = example_body(spec, media).map(|example| match example {
+                Value::String(text) if !media_type.contains("json") => text,
+                example => serde_json::to_string_pretty(&example).unwrap_or_default(),
+            });
+            let headers = HashMap::from([("Content-Type".to_string(), media_type.clone())]);
+            (body, Some(headers))
+        }
+        None => (None, None),
+    };
+    ResponseDefinition {
+        status,
+        body,
+        headers,
+        extra: Map::new(),
+    }
+}
+
+fn example_body(spec: &Value, media: &Value) -> Option<Value> {
+    if let Some(example) = media.get("example") {
+        return Some(example.clone());
+    }
+    if let Some(examples) = media.get("examples").and_then(Value::as_object)
+        && let Some((_, example)) = examples.iter().next()
+        && let Some(value) = resolve(spec, example).get("value")
+    {
+        return Some(value.clone());
+    }
+    media
+        .get("schema")
+        .map(|schema| schema_example(spec, schema, 0))
+}
+
+/// Builds a plausible value from a schema, preferring documented examples.
+fn schema_example(spec: &Value, schema: &Value, depth: usize) -> Value {
+    let schema = resolve(spec, schema);
+    if let Some(example) = schema.get("example") {
+        return example.clone();
+    }
+    if let Some(default) = schema.get("default") {
+        return default.clone();
+    }
+    if let Some(first) = schema.pointer("/enum/0") {
+        return first.clone();
+    }
+    if depth > MAX_EXAMPLE_DEPTH {
+        return Value::Null;
+    }
+    for combinator in ["allOf", "oneOf", "anyOf"] {
+        if let Some(variants) = schema.get(combinator).and_then(Value::as_array) {
+            if combinator != "allOf" {
+                return variants
+                    .first()
+                    .map(|v| schema_example(spec, v, depth + 1))
+                    .unwrap_or(Value::Null);
+            }
+            let mut merged = Map::new();
+            for variant in variants {
+                if let Value::Object(fields) = schema_example(spec, variant, depth + 1) {
+                    merged.extend(fields);
+                }
+            }
+            return Value::Object(merged);
+        }
+    }
+    match schema.get("type").and_then(Value::as_str) {
+        Some("array") => Value::Array(
+            schema
+                .get("items")
+                .map(|items| vec![schema_example(spec, items, depth + 1)])
+                .unwrap_or_default(),
+        ),
+        Some("string") => Value::String(
+            match schema.get("format").and_then(Value::as_str) {
+                Some("date-time") => "2024-01-01T00:00:00Z",
+                Some("date") => "2024-01-01",
+                Some("uuid") => "00000000-0000-0000-0000-000000000000",
+                Some("email") => "user@example.com",
+                _ => "string",
+            }
+            .to_string(),
+        ),
+        Some("integer") => Value::from(0),
+        Some("number") => Value::from(0.0),
+        Some("boolean") => Value::Bool(true),
+        _ => match schema.get("properties").and_then(Value::as_object) {
+            Some(properties) => Value::Object(
+                properties
+                    .iter()
+                    .map(|(name, property)| {
+                        (name.clone(), schema_example(spec, property, depth + 1))
+                    })
+                    .collect(),
+            ),
+            None if schema.get("type").is_some() => Value::Object(Map::new()),
+            None => Value::Null,
+        },
+    }
+}
+
+/// Follows local `$ref`s like `#/components/schemas/Pet`.
+fn resolve<'a>(spec: &'a Value, value: &'a Value) -> &'a Value {
+    let mut current = value;
+    for _ in 0..MAX_EXAMPLE_DEPTH {
+        match current.get("$ref").and_then(Value::as_str) {
+            Some(reference) if reference.starts_with('#') => match spec.pointer(&reference[1..]) {
+                Some(target) => current = target,
+                None => break,
+            },
+            _ => break,
+        }
+    }
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn status_of(responses: Value) -> u16 {
+        let spec = json!({"paths": {"/a": {"get": {"responses": responses}}}});
+        generate_stubs(&spec)[0].stub.response.status
+    }
+
+    #[test]
+    fn maps_path_templates_to_url_path_patterns() {
+        let spec = json!({
+            "servers": [{"url": "https://api.example.com/v1/"}],
+            "paths": {
+                "/pets/{petId}": {"get": {"operationId": "showPet", "responses": {}}},
+                "/files/{name}.json": {"delete": {"responses": {}}}
+            }
+        });
+        let stubs = generate_stubs(&spec);
+        let patterns: Vec<(&str, &str, &str)> = stubs
+            .iter()
+            .map(|s| {
+                (
+                    s.label.as_str(),
+                    s.stub.request.method.as_str(),
+                    s.stub.request.url_path_pattern.as_deref().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            patterns,
+            [
+                (
+                    "DELETE /files/{name}.json",
+                    "DELETE",
+                    "/v1/files/[^/]+\\.json"
+                ),
+                ("GET /pets/{petId} (showPet)", "GET", "/v1/pets/[^/]+"),
+            ]
+        );
+    }
+
+    #[test]
+    fn picks_the_lowest_success_status() {
+        assert_eq!(
+            status_of(json!({"404": {}, "201": {}, "200": {}, "default": {}})),
+            200
+        );
+        assert_eq!(status_of(json!({"500": {}, "2XX": {}, "204": {}})), 204);
+        assert_eq!(status_of(json!({"2XX": {}})), 200);
+    }
+
+    #[test]
+    fn falls_back_to_default_then_the_first_response() {
+        assert_eq!(status_of(json!({"default": {}, "500": {}})), 200);
+        assert_eq!(status_of(json!({"500": {}, "404": {}})), 404);
+        assert_eq!(status_of(json!({})), 200);
+    }
+
+    #[test]
+    fn serves_the_json_example_with_its_content_type() {
+        let spec = json!({"paths": {"/a": {"get": {"responses": {"200": {
+            "content": {"application/json": {"example": {"id": 1}}}
+        }}}}}});
+        let response = &generate_stubs(&spec)[0].stub.response;
+        assert_eq!(
+            serde_json::from_str::<Value>(response.body.as_deref().unwrap()).unwrap(),
+            json!({"id": 1})
+        );
+        assert_eq!(
+            response.headers.as_ref().unwrap()["Content-Type"],
+            "application/json"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod generator;
+pub mod model;
+pub mod openapi_screen;
@@ -0,0 +1,197 @@
+use crate::model::{Command, Message, ModelTrait};
+use crate::openapi::generator;
+use crate::openapi::generator::GeneratedStub;
+use crate::server::model::ReadOnlyServers;
+use crate::stub::model::StubMsg;
+use crate::wire_mock;
+use async_trait::async_trait;
+use std::error::Error;
+use std::path::Path;
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Clone, Debug)]
+pub struct OpenApiEntry {
+    pub generated: GeneratedStub,
+    pub checked: bool,
+}
+
+pub struct OpenApiModel {
+    pub selected_server_url: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub path_input: String,
+    pub entries: Vec<OpenApiEntry>,
+    pub selected_entry_index: usize,
+    pub status_message: Option<String>,
+}
+
+#[async_trait]
+impl ModelTrait<OpenApiMsg, OpenApiCommand> for OpenApiModel {
+    async fn apply_event(&mut self, event: OpenApiMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            OpenApiMsg::InputChar(c) => {
+                self.path_input.push(c);
+                Ok(())
+            }
+            OpenApiMsg::InputBackspace => {
+                self.path_input.pop();
+                Ok(())
+            }
+            OpenApiMsg::SelectNext => {
+                if !self.entries.is_empty() {
+                    self.selected_entry_index =
+                        (self.selected_entry_index + 1).min(self.entries.len() - 1);
+                }
+                Ok(())
+            }
+            OpenApiMsg::SelectPrevious => {
+                self.selected_entry_index = self.selected_entry_index.saturating_sub(1);
+                Ok(())
+            }
+            OpenApiMsg::ToggleSelected => {
+                if let Some(entry) = self.entries.get_mut(self.selected_entry_index) {
+                    entry.checked = !entry.checked;
+                }
+                Ok(())
+            }
+            OpenApiMsg::ToggleAll => {
+                let check = self.entries.iter().any(|e| !e.checked);
+                self.entries.iter_mut().for_each(|e| e.checked = check);
+                Ok(())
+            }
+            OpenApiMsg::GenerateRequested => {
+                self.command_sender
+                    .send(Command::OpenApi(OpenApiCommand::Generate))?;
+                Ok(())
+            }
+            OpenApiMsg::PushRequested => {
+                self.command_sender
+                    .send(Command::OpenApi(OpenApiCommand::PushChecked))?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: OpenApiCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            OpenApiCommand::Generate => self.generate(),
+            OpenApiCommand::PushChecked => self.push_checked(),
+        };
+        if let Err(err) = result {
+            self.status_message = Some(err.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl OpenApiModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            selected_server_url: None,
+            read_only_servers,
+            event_sender,
+            command_sender,
+            path_input: String::new(),
+            entries: vec![],
+            selected_entry_index: 0,
+            status_message: None,
+        }
+    }
+
+    pub fn selected_entry(&self) -> Option<&OpenApiEntry> {
+        self.entries.get(self.selected_entry_index)
+    }
+
+    fn generate(&mut self) -> Result<(), Box<dyn Error>> {
+        if self.path_input.trim().is_empty() {
+            return Err(Box::new(OpenApiError::NoPathGiven));
+        }
+        let spec = generator::load_spec(Path::new(self.path_input.trim()))?;
+        self.entries = generator::generate_stubs(&spec)
+            .into_iter()
+            .map(|generated| OpenApiEntry {
+                generated,
+                checked: false,
+            })
+            .collect();
+        self.selected_entry_index = 0;
+        self.status_message = Some(format!(
+            "{} operations found, check the ones to stub",
+            self.entries.len()
+        ));
+        Ok(())
+    }
+
+    fn push_checked(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .as_ref()
+            .ok_or(OpenApiError::NoServerSelected)?;
+        self.read_only_servers
+            .check(server_url, "generating stubs from OpenAPI")?;
+        if !self.entries.iter().any(|e| e.checked) {
+            return Err(Box::new(OpenApiError::NothingChecked));
+        }
+        // Entries are unchecked as they are created, so a retry only pushes
+        // the ones that failed
+        let mut created = 0;
+        let mut failures = vec![];
+        for entry in self.entries.iter_mut().filter(|e| e.checked) {
+            match wire_mock::client::create_stub(server_url, &entry.generated.stub) {
+                Ok(_) => {
+                    entry.checked = false;
+                    created += 1;
+                }
+                Err(err) => failures.push(format!("{} ({})", entry.generated.label, err)),
+            }
+        }
+        self.status_message = Some(if failures.is_empty() {
+            format!("Created {} stubs on {}", created, server_url)
+        } else {
+            format!(
+                "Created {} stubs on {}, {} failed and stay checked: {}",
+                created,
+                server_url,
+                failures.len(),
+                failures.join(", ")
+            )
+        });
+        self.event_sender
+            .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum OpenApiCommand {
+    Generate,
+    PushChecked,
+}
+
+#[derive(Clone, Debug)]
+pub enum OpenApiMsg {
+    InputChar(char),
+    InputBackspace,
+    SelectNext,
+    SelectPrevious,
+    ToggleSelected,
+    ToggleAll,
+    GenerateRequested,
+    PushRequested,
+}
+
+#[derive(Error, Debug)]
+pub enum OpenApiError {
+    #[error("No server selected")]
+    NoServerSelected,
+    #[error("Enter the path of an OpenAPI 3 YAML or JSON file first")]
+    NoPathGiven,
+    #[error("No operations checked")]
+    NothingChecked,
+}
@@ -0,0 +1,169 @@
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::openapi::model::OpenApiMsg;
+use crate::ui;
+use async_trait::async_trait;
+use crossterm::event::{Event, KeyCode};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct OpenApiScreen {
+    sender: Sender<Message>,
+}
+
+impl OpenApiScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        OpenApiScreen { sender }
+    }
+
+    fn get_entry_details(&self, app: &ApplicationModel) -> String {
+        match app.openapi_model.selected_entry() {
+            None => "Enter an OpenAPI 3 YAML or JSON file and press Enter".to_string(),
+            Some(entry) => serde_json::to_string_pretty(&entry.generated.stub)
+                .unwrap_or_else(|_| format!("{:#?}", entry.generated.stub)),
+        }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for OpenApiScreen {
+    fn scope(&self) -> Scope {
+        Scope::OpenApi
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.openapi_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title =
+            ui::widgets::title_paragraph("Wire Mock - Generate Stubs From OpenAPI", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        // Spec path input
+        let input = Paragraph::new(format!("{}█", model.path_input)).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("OpenAPI 3 spec (YAML or JSON)"),
+        );
+        frame.render_widget(input, main_layout[1]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(main_layout[2]);
+
+        // Operation checklist (left side)
+        let items: Vec<ListItem> = model
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let check = if entry.checked { "[x]" } else { "[ ]" };
+                let (marker, style) = if i == model.selected_entry_index {
+                    ("▶", app.theme.selected())
+                } else {
+                    (" ", app.theme.text())
+                };
+                ListItem::new(format!("{} {} {}", marker, check, entry.generated.label))
+                    .style(style)
+            })
+            .collect();
+        let checked = model.entries.iter().filter(|e| e.checked).count();
+        let operations = List::new(items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Operations ({} checked)", checked)),
+        );
+        frame.render_widget(operations, content_layout[0]);
+
+        // Generated stub (right side)
+        let details = Paragraph::new(self.get_entry_details(app))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Generated stub"),
+            )
+            .style(app.theme.text())
+            .wrap(Wrap { trim: false });
+        frame.render_widget(details, content_layout[1]);
+
+        // Status
+        let status = Paragraph::new(model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::OpenApi, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::Push => {
+                self.sender
+                    .send(Message::OpenApi(OpenApiMsg::PushRequested))?;
+                Ok(())
+            }
+            Action::CheckAll => {
+                self.sender.send(Message::OpenApi(OpenApiMsg::ToggleAll))?;
+                Ok(())
+            }
+            Action::Generate => {
+                self.sender
+                    .send(Message::OpenApi(OpenApiMsg::GenerateRequested))?;
+                Ok(())
+            }
+            Action::Check => {
+                self.sender
+                    .send(Message::OpenApi(OpenApiMsg::ToggleSelected))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::OpenApi(OpenApiMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::OpenApi(OpenApiMsg::SelectNext))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(c) => {
+                    self.sender
+                        .send(Message::OpenApi(OpenApiMsg::InputChar(c)))?;
+                    Ok(())
+                }
+                KeyCode::Backspace => {
+                    self.sender
+                        .send(Message::OpenApi(OpenApiMsg::InputBackspace))?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::admin::model::{AdminAction, AdminMsg};
+use crate::alerts::model::AlertMsg;
+use crate::journal::model::JournalMsg;
+use crate::keymap::action::{Action, Scope};
+use crate::model::{GlobalMsg, Message};
+use crate::server::model::ServerMsg;
+use crate::stub::copy::CopyFormat;
+use crate::stub::model::StubMsg;
+use crate::transfer::model::TransferMode;
+
+/// A command the palette can run from any screen.
+#[derive(Clone, Debug)]
+pub struct PaletteEntry {
+    pub title: String,
+    /// The key that runs the same command on its own screen.
+    pub binding: Option<(Scope, Action)>,
+    /// Sent in order when the command runs.
+    pub messages: Vec<Message>,
+    /// Whether the command works on the stubs of the selected server.
+    pub needs_server: bool,
+    /// Whether the command changes the server, so read-only servers leave it
+    /// out.
+    pub mutating: bool,
+}
+
+impl PaletteEntry {
+    fn new(title: impl Into<String>, messages: Vec<Message>) -> Self {
+        PaletteEntry {
+            title: title.into(),
+            binding: None,
+            messages,
+            needs_server: false,
+            mutating: false,
+        }
+    }
+
+    fn bound(mut self, scope: Scope, action: Action) -> Self {
+        self.binding = Some((scope, action));
+        self
+    }
+
+    /// Marks a command that works on the selected server. A command on the
+    /// stub list opens that list first, so its outcome is visible; one that
+    /// opens a screen of its own only needs the server's stubs when they are
+    /// not loaded yet, which the palette sees to when it runs.
+    fn on_server(mut self) -> Self {
+        let opens_screen = matches!(self.messages.first(), Some(Message::Global(_)));
+        if !opens_screen {
+            self.messages
+                .insert(0, global(GlobalMsg::SwitchToStubScreen));
+        }
+        self.needs_server = true;
+        self
+    }
+
+    fn mutating(mut self) -> Self {
+        self.mutating = true;
+        self.on_server()
+    }
+}
+
+fn global(msg: GlobalMsg) -> Message {
+    Message::Global(msg)
+}
+
+/// Runs a stub list command; `on_server` shows the stub list first, so its
+/// outcome is visible.
+fn on_stubs(msg: StubMsg) -> Vec<Message> {
+    vec![Message::Stub(msg)]
+}
+
+/// Every command of the application, in the order the palette lists them
+/// before anything is typed.
+pub fn all_entries() -> Vec<PaletteEntry> {
+    let mut entries = vec![
+        PaletteEntry::new(
+            "Switch server",
+            vec![global(GlobalMsg::SwitchToServerSelectionScreen)],
+        ),
+        PaletteEntry::new(
+            "Edit server connection",
+            vec![global(GlobalMsg::SwitchToConnectionEditScreen)],
+        )
+        .bound(Scope::ServerSelection, Action::EditServer),
+        PaletteEntry::new(
+            "Compare servers",
+            vec![global(GlobalMsg::SwitchToCompareScreen)],
+        )
+        .bound(Scope::ServerSelection, Action::CompareServers),
+        PaletteEntry::new(
+            "Check server health",
+            vec![Message::Server(ServerMsg::ProbeRequested)],
+        ),
+        PaletteEntry::new("Show stubs", vec![global(GlobalMsg::SwitchToStubScreen)]).on_server(),
+        PaletteEntry::new("Refresh stubs", on_stubs(StubMsg::ReadAllStubsRequested))
+            .bound(Scope::Stubs, Action::Refresh)
+            .on_server(),
+        PaletteEntry::new(
+            "Toggle auto refresh",
+            on_stubs(StubMsg::ToggleAutoRefreshStubsRequested),
+        )
+        .bound(Scope::Stubs, Action::AutoRefresh)
+        .on_server(),
+        PaletteEntry::new(
+            "Delete selected stub",
+            on_stubs(StubMsg::DeleteSelectedRequested),
+        )
+        .bound(Scope::Stubs, Action::Delete)
+        .mutating(),
+    ];
+    for format in [
+        CopyFormat::MappingJson,
+        CopyFormat::RegisterCurl,
+        CopyFormat::RequestCurl,
+    ] {
+        entries.push(
+            PaletteEntry::new(
+                format!("Export selected stub as {}", format.description()),
+                on_stubs(StubMsg::CopySelectedRequested(format)),
+            )
+            .bound(Scope::Stubs, Action::CopyAs)
+            .on_server(),
+        );
+    }
+    entries.extend([
+        PaletteEntry::new(
+            "Copy stubs to another server",
+            vec![global(GlobalMsg::SwitchToTransferScreen(
+                TransferMode::Copy,
+            ))],
+        )
+        .bound(Scope::Stubs, Action::CopyToServer)
+        .on_server(),
+        PaletteEntry::new(
+            "Move stubs to another server",
+            vec![global(GlobalMsg::SwitchToTransferScreen(
+                TransferMode::Move,
+            ))],
+        )
+        .bound(Scope::Stubs, Action::MoveToServer)
+        .mutating(),
+        PaletteEntry::new(
+            "Import stubs from files",
+            vec![global(GlobalMsg::SwitchToImportScreen)],
+        )
+        .bound(Scope::Stubs, Action::Import)
+        .mutating(),
+        PaletteEntry::new(
+            "Generate stubs from OpenAPI",
+            vec![global(GlobalMsg::SwitchToOpenApiScreen)],
+        )
+        .bound(Scope::Stubs, Action::FromOpenApi)
+        .mutating(),
+        PaletteEntry::new(
+            "Generate stubs from HAR",
+            vec![global(GlobalMsg::SwitchToHarScreen)],
+        )
+        .bound(Scope::Stubs, Action::FromHar)
+        .mutating(),
+        PaletteEntry::new(
+            "Watch and sync a directory",
+            vec![global(GlobalMsg::SwitchToSyncScreen)],
+        )
+        .bound(Scope::Stubs, Action::WatchDirectory)
+        .mutating(),
+        PaletteEntry::new(
+            "Open request journal",
+            vec![global(GlobalMsg::SwitchToJournalScreen)],
+        )
+        .bound(Scope::Stubs, Action::RequestJournal)
+        .on_server(),
+        PaletteEntry::new(
+            "Show unmatched requests only",
+            vec![
+                global(GlobalMsg::SwitchToJournalScreen),
+                Message::Journal(JournalMsg::SetUnmatchedOnly(true)),
+            ],
+        )
+        .bound(Scope::Journal, Action::UnmatchedOnly)
+        .on_server(),
+        PaletteEntry::new(
+            "Test a request",
+            vec![global(GlobalMsg::SwitchToTesterScreen)],
+        )
+        .bound(Scope::Stubs, Action::TestRequest)
+        .on_server(),
+        PaletteEntry::new("Lint report", vec![global(GlobalMsg::SwitchToLintScreen)])
+            .bound(Scope::Stubs, Action::LintReport)
+            .on_server(),
+        PaletteEntry::new(
+            "Stub coverage",
+            vec![global(GlobalMsg::SwitchToCoverageScreen)],
+        )
+        .bound(Scope::Stubs, Action::Coverage)
+        .on_server(),
+        PaletteEntry::new(
+            "Live traffic",
+            vec![global(GlobalMsg::SwitchToDashboardScreen)],
+        )
+        .bound(Scope::Stubs, Action::LiveTraffic)
+        .on_server(),
+        PaletteEntry::new(
+            "Cycle alert mode",
+            vec![Message::Alert(AlertMsg::CycleDesktopNotification)],
+        )
+        .bound(Scope::Stubs, Action::AlertMode)
+        .on_server(),
+        PaletteEntry::new("Housekeeping", vec![global(GlobalMsg::SwitchToAdminScreen)])
+            .bound(Scope::Stubs, Action::Housekeeping)
+            .mutating(),
+    ]);
+    for action in AdminAction::ALL {
+        entries.push(
+            PaletteEntry::new(
+                action.description(),
+                vec![
+                    global(GlobalMsg::SwitchToAdminScreen),
+                    Message::Admin(AdminMsg::Request(action)),
+                ],
+            )
+            .mutating(),
+        );
+    }
+    entries.push(PaletteEntry::new("Quit", vec![Message::QuitRequested]));
+    entries
+}
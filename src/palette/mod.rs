@@ -0,0 +1,3 @@
+pub mod entries;
+pub mod model;
+pub mod palette_overlay;
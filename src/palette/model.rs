@@ -0,0 +1,152 @@
+use crate::model::{GlobalMsg, Message, ModelTrait};
+use crate::palette::entries::{all_entries, PaletteEntry};
+use async_trait::async_trait;
+use std::error::Error;
+use tokio::sync::broadcast::Sender;
+
+/// The command palette: what was typed and the commands matching it.
+pub struct PaletteModel {
+    event_sender: Sender<Message>,
+    pub entries: Vec<PaletteEntry>,
+    pub query: String,
+    /// Indices into `entries`, best match first.
+    pub matches: Vec<usize>,
+    pub selected_index: usize,
+    /// Whether the stubs of the selected server are loaded, so commands on
+    /// it can skip opening the stub list.
+    server_open: bool,
+}
+
+#[async_trait]
+impl ModelTrait<PaletteMsg, PaletteCommand> for PaletteModel {
+    async fn apply_event(&mut self, event: PaletteMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            PaletteMsg::InputChar(c) => {
+                self.query.push(c);
+                self.update_matches();
+                Ok(())
+            }
+            PaletteMsg::InputBackspace => {
+                self.query.pop();
+                self.update_matches();
+                Ok(())
+            }
+            PaletteMsg::SelectNext => {
+                self.selected_index =
+                    (self.selected_index + 1).min(self.matches.len().saturating_sub(1));
+                Ok(())
+            }
+            PaletteMsg::SelectPrevious => {
+                self.selected_index = self.selected_index.saturating_sub(1);
+                Ok(())
+            }
+            PaletteMsg::RunSelectedRequested => {
+                let Some(entry) = self.selected_entry() else {
+                    return Ok(());
+                };
+                let mut messages = entry.messages.clone();
+                let opens_stubs = matches!(
+                    messages.first(),
+                    Some(Message::Global(GlobalMsg::SwitchToStubScreen))
+                );
+                if entry.needs_server && !self.server_open && !opens_stubs {
+                    messages.insert(0, Message::Global(GlobalMsg::SwitchToStubScreen));
+                }
+                self.event_sender
+                    .send(Message::Global(GlobalMsg::CloseOverlay))?;
+                for message in messages {
+                    self.event_sender.send(message)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, _: PaletteCommand) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+}
+
+impl PaletteModel {
+    pub fn new(event_sender: Sender<Message>) -> Self {
+        Self {
+            event_sender,
+            entries: vec![],
+            query: String::new(),
+            matches: vec![],
+            selected_index: 0,
+            server_open: false,
+        }
+    }
+
+    /// Starts over with the commands that make sense right now: nothing that
+    /// needs a server before one is picked, and nothing that changes a
+    /// read-only server.
+    pub fn reset(&mut self, server_selected: bool, read_only: bool, server_open: bool) {
+        self.server_open = server_open;
+        self.entries = all_entries()
+            .into_iter()
+            .filter(|e| server_selected || !e.needs_server)
+            .filter(|e| !(read_only && e.mutating))
+            .collect();
+        self.query.clear();
+        self.update_matches();
+    }
+
+    pub fn selected_entry(&self) -> Option<&PaletteEntry> {
+        self.matches
+            .get(self.selected_index)
+            .map(|&index| &self.entries[index])
+    }
+
+    fn update_matches(&mut self) {
+        let mut scored: Vec<(i64, usize)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| fuzzy_score(&self.query, &entry.title).map(|s| (s, i)))
+            .collect();
+        // Stable, so equally good matches keep the palette's order
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        self.matches = scored.into_iter().map(|(_, i)| i).collect();
+        self.selected_index = 0;
+    }
+}
+
+/// Scores how well `query` matches `text`. Every character of the query has
+/// to appear in the text in the same order, ignoring case and spaces; matches
+/// at the start of a word and runs of adjacent characters score higher, gaps
+/// lower. An empty query matches everything equally.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text: Vec<char> = text.chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous = None;
+    for wanted in query.chars().filter(|c| !c.is_whitespace()) {
+        let found =
+            (position..text.len()).find(|&i| text[i].to_lowercase().eq(wanted.to_lowercase()))?;
+        score += 1;
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 8;
+        }
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 5;
+        }
+        score -= (found - position).min(3) as i64;
+        position = found + 1;
+        previous = Some(found);
+    }
+    Some(score)
+}
+
+#[derive(Clone, Debug)]
+pub enum PaletteCommand {}
+
+#[derive(Clone, Debug)]
+pub enum PaletteMsg {
+    InputChar(char),
+    InputBackspace,
+    SelectNext,
+    SelectPrevious,
+    RunSelectedRequested,
+}
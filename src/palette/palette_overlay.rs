@@ -0,0 +1,149 @@
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::palette::model::PaletteMsg;
+use crate::ui;
+use async_trait::async_trait;
+use crossterm::event::{Event, KeyCode};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::prelude::{Line, Span, Style};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+/// Most commands listed at once; the list scrolls with the selection.
+const MAX_VISIBLE: usize = 12;
+
+/// Modal to search every command of the application by name and run it.
+pub struct PaletteOverlay {
+    sender: Sender<Message>,
+}
+
+impl PaletteOverlay {
+    pub fn new(sender: Sender<Message>) -> Self {
+        PaletteOverlay { sender }
+    }
+
+    /// The keys bound to the command on its own screen, if any.
+    fn keys_text(&self, app: &ApplicationModel, binding: Option<(Scope, Action)>) -> String {
+        binding
+            .and_then(|(scope, action)| {
+                app.keymap
+                    .bindings(scope)
+                    .iter()
+                    .find(|b| b.action == action)
+            })
+            .map(|b| b.keys_text())
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for PaletteOverlay {
+    fn scope(&self) -> Scope {
+        Scope::Palette
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.palette_model;
+        let visible = model.matches.len().clamp(1, MAX_VISIBLE);
+        let height = (visible as u16 + 4).min(frame.area().height.saturating_sub(2));
+        let area = ui::widgets::centered_rect(60, height, frame.area());
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("Commands (Esc to close)");
+        let inner = block.inner(area);
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(2), Constraint::Min(0)])
+            .split(inner);
+
+        // Query
+        let query = Paragraph::new(Line::from(vec![
+            Span::styled("> ", app.theme.accent()),
+            Span::raw(model.query.as_str()),
+            Span::styled("_", app.theme.muted()),
+        ]));
+        frame.render_widget(query, layout[0]);
+
+        // Matching commands with their keys
+        let rows = layout[1].height as usize;
+        let offset = model.selected_index.saturating_sub(rows.saturating_sub(1));
+        let width = layout[1].width as usize;
+        let items: Vec<ListItem> = model
+            .matches
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .map(|(i, &index)| {
+                let entry = &model.entries[index];
+                let keys = self.keys_text(app, entry.binding);
+                let padding = width
+                    .saturating_sub(entry.title.chars().count() + keys.chars().count() + 2)
+                    .max(1);
+                let style = if i == model.selected_index {
+                    app.theme.selected()
+                } else {
+                    Style::default()
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" {}", entry.title), style),
+                    Span::raw(" ".repeat(padding)),
+                    Span::styled(keys, app.theme.muted()),
+                ]))
+            })
+            .collect();
+        if items.is_empty() {
+            let none = Paragraph::new(" No matching command").style(app.theme.muted());
+            frame.render_widget(none, layout[1]);
+        } else {
+            frame.render_widget(List::new(items), layout[1]);
+        }
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back | Action::CommandPalette => {
+                self.sender.send(Message::Global(GlobalMsg::CloseOverlay))?;
+                Ok(())
+            }
+            Action::Confirm => {
+                self.sender
+                    .send(Message::Palette(PaletteMsg::RunSelectedRequested))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::Palette(PaletteMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Palette(PaletteMsg::SelectNext))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(c) => {
+                    self.sender
+                        .send(Message::Palette(PaletteMsg::InputChar(c)))?;
+                    Ok(())
+                }
+                KeyCode::Backspace => {
+                    self.sender
+                        .send(Message::Palette(PaletteMsg::InputBackspace))?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
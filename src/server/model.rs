@@ -1,29 +1,189 @@
-use crate::model::{Command, Message, ModelTrait};
+use crate::keymap::KeyOverrides;
+use crate::model::{Command, GlobalMsg, Message, ModelTrait};
+use crate::ui::theme::ThemeDefinitions;
+use crate::wire_mock;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use thiserror::Error;
 use tokio::sync::broadcast::Sender;
+use tokio::time::interval;
+
+/// How often every configured server is probed.
+const PROBE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Probes give up after this long, so a dead host shows up as unreachable.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Debug)]
+pub enum ServerHealth {
+    Up {
+        latency: Duration,
+        version: Option<String>,
+        mapping_count: Option<usize>,
+    },
+    Down(String),
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ServerConfiguration {
-    pub server_list: Vec<String>,
+    pub server_list: Vec<ServerProfile>,
     pub selected_server_index: Option<usize>,
+    /// Key bindings replacing the defaults, per screen and action.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub keys: KeyOverrides,
+    /// Name of the color theme, built-in or from `themes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub themes: ThemeDefinitions,
+}
+
+/// A configured server. Older configurations list bare URLs, which still load
+/// as profiles without a name.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "ProfileEntry")]
+pub struct ServerProfile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub url: String,
+    /// Shared servers that must never be changed from this tool.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub read_only: bool,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ProfileEntry {
+    Url(String),
+    Profile {
+        name: Option<String>,
+        url: String,
+        #[serde(default)]
+        read_only: bool,
+    },
+}
+
+impl From<ProfileEntry> for ServerProfile {
+    fn from(entry: ProfileEntry) -> Self {
+        match entry {
+            ProfileEntry::Url(url) => ServerProfile {
+                name: None,
+                url,
+                read_only: false,
+            },
+            ProfileEntry::Profile {
+                name,
+                url,
+                read_only,
+            } => ServerProfile {
+                name,
+                url,
+                read_only,
+            },
+        }
+    }
 }
 
 impl Default for ServerConfiguration {
     fn default() -> Self {
         Self {
-            server_list: vec!["http://localhost:8080".to_string()],
+            server_list: vec![ServerProfile {
+                name: None,
+                url: "http://localhost:8080".to_string(),
+                read_only: false,
+            }],
             selected_server_index: Some(0),
+            keys: HashMap::new(),
+            theme: None,
+            themes: HashMap::new(),
         }
     }
 }
 
+impl ServerConfiguration {
+    pub fn selected_server(&self) -> Option<&ServerProfile> {
+        self.selected_server_index
+            .and_then(|i| self.server_list.get(i))
+    }
+
+    pub fn find_profile(&self, name: &str) -> Option<&ServerProfile> {
+        self.server_list
+            .iter()
+            .find(|p| p.name.as_deref() == Some(name))
+    }
+
+    /// Whether any profile for `url` is marked read-only.
+    pub fn is_read_only(&self, url: &str) -> bool {
+        self.server_list.iter().any(|p| p.url == url && p.read_only)
+    }
+}
+
+/// Loads the server configuration from `path`, or from the default confy
+/// location when no path is given.
+pub fn load_configuration(path: Option<&Path>) -> Result<ServerConfiguration, confy::ConfyError> {
+    match path {
+        Some(path) => confy::load_path(path),
+        None => confy::load("wiremock-tui", "servers"),
+    }
+}
+
+/// The servers that must not be changed. Every model that writes to a server
+/// holds a clone and checks it right before writing, so neither a stale
+/// screen nor a message sent from elsewhere gets past it.
+#[derive(Clone, Default)]
+pub struct ReadOnlyServers(Arc<RwLock<ReadOnlyState>>);
+
+#[derive(Default)]
+struct ReadOnlyState {
+    /// Treats every server as read-only, as requested on the command line.
+    all: bool,
+    urls: HashSet<String>,
+}
+
+impl ReadOnlyServers {
+    pub fn set_all(&self, all: bool) {
+        self.0.write().unwrap().all = all;
+    }
+
+    pub fn set_urls(&self, urls: HashSet<String>) {
+        self.0.write().unwrap().urls = urls;
+    }
+
+    pub fn contains(&self, server_url: &str) -> bool {
+        let state = self.0.read().unwrap();
+        state.all || state.urls.contains(server_url)
+    }
+
+    /// Refuses `action` when it would change a read-only server.
+    pub fn check(&self, server_url: &str, action: &str) -> Result<(), ReadOnlyError> {
+        if self.contains(server_url) {
+            return Err(ReadOnlyError(action.to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("Read-only server: {0} is disabled")]
+pub struct ReadOnlyError(String);
+
 pub struct ServerModel {
     pub msg_sender: Sender<Message>,
     pub command_sender: Sender<Command>,
     pub server_list: Vec<String>,
     pub current_selected_server_index: Option<usize>,
+    /// Alternate configuration file given on the command line.
+    pub config_path: Option<PathBuf>,
+    /// Server given on the command line, opened instead of the selection.
+    pub startup_server: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub health: HashMap<String, ServerHealth>,
+    pub probe_task: Option<tokio::task::JoinHandle<()>>,
 }
 
 #[async_trait]
@@ -42,14 +202,46 @@ impl ModelTrait<ServerMsg, ServerCommand> for ServerModel {
                 self.delete_selected_server();
                 Ok(())
             }
+            ServerMsg::Select(index) => {
+                if index < self.server_list.len() {
+                    self.current_selected_server_index = Some(index);
+                }
+                Ok(())
+            }
             ServerMsg::LoadConfigurationRequested => {
                 self.command_sender
                     .send(Command::Server(ServerCommand::LoadConfiguration))?;
                 Ok(())
             }
             ServerMsg::ConfigurationLoaded(configuration) => {
-                self.server_list = configuration.server_list;
+                self.read_only_servers.set_urls(
+                    configuration
+                        .server_list
+                        .iter()
+                        .filter(|profile| profile.read_only)
+                        .map(|profile| profile.url.clone())
+                        .collect(),
+                );
+                self.server_list = configuration
+                    .server_list
+                    .into_iter()
+                    .map(|profile| profile.url)
+                    .collect();
                 self.current_selected_server_index = configuration.selected_server_index;
+                if let Some(server) = self.startup_server.take() {
+                    self.select_startup_server(server);
+                    self.msg_sender
+                        .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                }
+                self.start_probing();
+                Ok(())
+            }
+            ServerMsg::ProbeRequested => {
+                self.probe_servers();
+                Ok(())
+            }
+            ServerMsg::HealthProbed(server, health) => {
+                self.health.insert(server, health);
                 Ok(())
             }
         }
@@ -58,8 +250,7 @@ impl ModelTrait<ServerMsg, ServerCommand> for ServerModel {
     async fn handle_command(&mut self, command: ServerCommand) -> Result<(), Box<dyn Error>> {
         match command {
             ServerCommand::LoadConfiguration => {
-                let server_configuration: ServerConfiguration =
-                    confy::load("wiremock-tui", "servers")?;
+                let server_configuration = load_configuration(self.config_path.as_deref())?;
                 self.msg_sender
                     .send(Message::Server(ServerMsg::ConfigurationLoaded(
                         server_configuration,
@@ -71,23 +262,107 @@ impl ModelTrait<ServerMsg, ServerCommand> for ServerModel {
 }
 
 impl ServerModel {
-    pub fn new(event_sender: Sender<Message>, command_sender: Sender<Command>) -> Self {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
         Self {
             msg_sender: event_sender,
             command_sender,
             server_list: vec![],
             current_selected_server_index: None,
+            config_path: None,
+            startup_server: None,
+            read_only_servers,
+            health: HashMap::new(),
+            probe_task: None,
+        }
+    }
+
+    /// Selects the server given at startup, adding it to the list for this
+    /// session when it is not configured.
+    fn select_startup_server(&mut self, server: String) {
+        let index = match self.server_list.iter().position(|s| *s == server) {
+            Some(index) => index,
+            None => {
+                self.server_list.push(server);
+                self.server_list.len() - 1
+            }
+        };
+        self.current_selected_server_index = Some(index);
+    }
+
+    pub fn is_read_only(&self, server: &str) -> bool {
+        self.read_only_servers.contains(server)
+    }
+
+    /// The selected server, if its last probe found it unreachable.
+    pub fn selected_server_down(&self) -> Option<&str> {
+        let server = self.current_selected_server()?;
+        match self.health.get(server) {
+            Some(ServerHealth::Down(reason)) => Some(reason),
+            _ => None,
+        }
+    }
+
+    /// Whether some server has not answered its first probe yet.
+    pub fn is_probing(&self) -> bool {
+        self.probe_task.is_some()
+            && self
+                .server_list
+                .iter()
+                .any(|server| !self.health.contains_key(server))
+    }
+
+    fn start_probing(&mut self) {
+        if self.probe_task.is_some() {
+            return;
+        }
+        let sender = self.msg_sender.clone();
+        let task = tokio::spawn(async move {
+            let mut interval = interval(PROBE_INTERVAL);
+            loop {
+                interval.tick().await;
+                if sender
+                    .send(Message::Server(ServerMsg::ProbeRequested))
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+        self.probe_task = Some(task);
+    }
+
+    /// Probes each server on a blocking thread so slow hosts never stall the UI.
+    fn probe_servers(&self) {
+        for server in self.server_list.clone() {
+            let sender = self.msg_sender.clone();
+            tokio::task::spawn_blocking(move || {
+                let started = Instant::now();
+                let health = match wire_mock::client::get_server_info(&server, PROBE_TIMEOUT) {
+                    Ok(info) => ServerHealth::Up {
+                        latency: started.elapsed(),
+                        version: info.version,
+                        mapping_count: info.mapping_count,
+                    },
+                    Err(err) => ServerHealth::Down(err.to_string()),
+                };
+                let _ = sender.send(Message::Server(ServerMsg::HealthProbed(server, health)));
+            });
         }
     }
 
-    fn update_server_list(self: &mut Self, server_list: Vec<String>) {
+    #[allow(dead_code)] // server management is not wired up yet
+    fn update_server_list(&mut self, server_list: Vec<String>) {
         self.server_list = server_list;
-        if self.server_list.len() > 0 {
+        if !self.server_list.is_empty() {
             self.current_selected_server_index = Some(0);
         }
     }
 
-    fn change_server_selection_up(self: &mut Self) {
+    fn change_server_selection_up(&mut self) {
         if self.current_selected_server_index.is_none() {
             return;
         }
@@ -103,7 +378,7 @@ impl ServerModel {
             .and_then(|i| self.server_list.get(i))
     }
 
-    fn change_server_selection_down(self: &mut Self) {
+    fn change_server_selection_down(&mut self) {
         if self.current_selected_server_index.is_none() {
             return;
         }
@@ -112,10 +387,12 @@ impl ServerModel {
         self.current_selected_server_index = Some(next_index);
     }
 
+    #[allow(dead_code)] // server management is not wired up yet
     fn add_new_server(&self, server_url: String) {
         println!("Adding new server: {server_url}");
     }
 
+    #[allow(clippy::needless_return)] // placeholder until deleting is implemented
     fn delete_selected_server(&self) {
         if self.current_selected_server_index.is_none() {
             return;
@@ -134,5 +411,9 @@ pub enum ServerMsg {
     ConfigurationLoaded(ServerConfiguration),
     ChangeSelectionUp,
     ChangeSelectionDown,
+    #[allow(dead_code)] // no key deletes servers yet
     DeleteSelectedServer,
+    Select(usize),
+    ProbeRequested,
+    HealthProbed(String, ServerHealth),
 }
@@ -1,10 +1,9 @@
+use crate::keymap::action::{Action, Scope};
 use crate::model::ScreenTrait;
 use crate::model::{ApplicationModel, GlobalMsg, Message};
 use crate::ui;
 use async_trait::async_trait;
-use crossterm::event::{Event, KeyCode};
 use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::widgets::Paragraph;
 use ratatui::Frame;
 use tokio::sync::broadcast::Sender;
 
@@ -20,7 +19,11 @@ impl ServerEditScreen {
 
 #[async_trait]
 impl ScreenTrait for ServerEditScreen {
-    fn draw(&self, _: &ApplicationModel, frame: &mut Frame) {
+    fn scope(&self) -> Scope {
+        Scope::ServerEdit
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
         let main_layout = Layout::default()
             .direction(Direction::Vertical)
             .constraints(vec![
@@ -31,40 +34,25 @@ impl ScreenTrait for ServerEditScreen {
             .split(frame.area());
 
         // Title
-        let title = ui::widgets::title_paragraph("Wire Mock  - Edit Server Connection");
+        let title = ui::widgets::title_paragraph("Wire Mock  - Edit Server Connection", &app.theme);
         frame.render_widget(title, main_layout[0]);
 
         // Commands
-        let commands = vec!["q : Quit", "Enter : Confirm"];
-
-        let control_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(vec![
-                Constraint::Ratio(1, commands.len() as u32);
-                commands.len()
-            ])
-            .split(main_layout[2]);
-
-        for (index, command) in commands.iter().enumerate() {
-            let paragraph = Paragraph::new(*command);
-            frame.render_widget(paragraph, control_layout[index]);
-        }
+        let commands = app.keymap.commands(Scope::ServerEdit, &[]);
+        ui::widgets::render_commands(frame, main_layout[2], &commands);
     }
 
-    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
-        match event {
-            Event::Key(key) => match key.code {
-                KeyCode::Char('q') => {
-                    self.sender.send(Message::QuitRequested)?;
-                    Ok(())
-                }
-                KeyCode::Enter | KeyCode::Char('k') => {
-                    self.sender
-                        .send(Message::Global(GlobalMsg::SwitchToServerSelectionScreen))?;
-                    Ok(())
-                }
-                _ => Ok(()),
-            },
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Confirm => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToServerSelectionScreen))?;
+                Ok(())
+            }
             _ => Ok(()),
         }
     }
@@ -1,12 +1,11 @@
+use crate::keymap::action::{Action, Scope};
 use crate::model::ScreenTrait;
 use crate::model::{ApplicationModel, GlobalMsg, Message};
 use crate::server::model::ServerMsg;
 use crate::ui;
+use crate::ui::mouse::{self, MouseGesture};
 use async_trait::async_trait;
-use crossterm::event::{Event, KeyCode};
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::prelude::{Color, Modifier, Style};
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::Frame;
 use tokio::sync::broadcast::Sender;
 
@@ -18,106 +17,117 @@ impl ServerSelectionScreen {
     pub fn new(sender: Sender<Message>) -> Self {
         ServerSelectionScreen { sender }
     }
+
+    /// The areas of the title, server list and commands.
+    fn layout(&self, area: Rect) -> [Rect; 3] {
+        Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+        ])
+        .areas(area)
+    }
 }
 
 #[async_trait]
 impl ScreenTrait for ServerSelectionScreen {
+    fn scope(&self) -> Scope {
+        Scope::ServerSelection
+    }
+
     fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
-        let main_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(vec![
-                Constraint::Length(3),
-                Constraint::Min(0),
-                Constraint::Length(3),
-            ])
-            .split(frame.area());
+        let [title_area, list_area, commands_area] = self.layout(frame.area());
 
         // Title
-        let title = ui::widgets::title_paragraph("Wire Mock - Select Server Connection");
-        frame.render_widget(title, main_layout[0]);
+        let title =
+            ui::widgets::title_paragraph("Wire Mock - Select Server Connection", &app.theme);
+        frame.render_widget(title, title_area);
 
         // Server list display
-        let items: Vec<ListItem> = app
-            .server_model
-            .server_list
-            .iter()
-            .enumerate()
-            .map(|(i, server)| {
-                let style = if i
-                    == app
-                        .server_model
-                        .current_selected_server_index
-                        .unwrap_or(999)
-                {
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD)
-                } else {
-                    Style::default()
-                };
-                ListItem::new(format!("▶ {}", server)).style(style)
-            })
-            .collect();
-
-        let server_list = List::new(items).block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Server selection"),
+        let server_list = ui::widgets::server_list(
+            &app.server_model.server_list,
+            app.server_model.current_selected_server_index,
+            "Server selection",
+            Some(&app.server_model.health),
+            &app.theme,
         );
-        frame.render_widget(server_list, main_layout[1]);
+        frame.render_widget(server_list, list_area);
 
         // Commands
-        let commands = vec![
-            "↑ / k : Up",
-            "↓ / j : Down",
-            "e : Edit server connection",
-            "Enter : Confirm",
-        ];
-
-        let control_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(vec![
-                Constraint::Ratio(1, commands.len() as u32);
-                commands.len()
-            ])
-            .split(main_layout[2]);
+        let commands = app.keymap.commands(Scope::ServerSelection, &[]);
+        ui::widgets::render_commands(frame, commands_area, &commands);
+    }
 
-        for (index, command) in commands.iter().enumerate() {
-            let paragraph = Paragraph::new(*command);
-            frame.render_widget(paragraph, control_layout[index]);
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::Server(ServerMsg::ChangeSelectionUp))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender
+                    .send(Message::Server(ServerMsg::ChangeSelectionDown))?;
+                Ok(())
+            }
+            Action::EditServer => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToConnectionEditScreen))?;
+                Ok(())
+            }
+            Action::CompareServers => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToCompareScreen))?;
+                Ok(())
+            }
+            Action::Confirm => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            _ => Ok(()),
         }
     }
 
-    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
-        match event {
-            Event::Key(key) => match key.code {
-                KeyCode::Char('q') => {
-                    self.sender.send(Message::QuitRequested)?;
-                    Ok(())
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    self.sender
-                        .send(Message::Server(ServerMsg::ChangeSelectionUp))?;
-                    Ok(())
-                }
-                KeyCode::Down | KeyCode::Char('j') => {
+    /// Clicks select a server, a double-click opens its stubs and the wheel
+    /// moves the selection.
+    fn handle_mouse(
+        &self,
+        app: &ApplicationModel,
+        area: Rect,
+        gesture: MouseGesture,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let [_, list, _] = self.layout(area);
+        let server_count = app.server_model.server_list.len();
+        match gesture {
+            MouseGesture::Click(position) => {
+                if let Some(index) = mouse::list_row(list, position, server_count) {
                     self.sender
-                        .send(Message::Server(ServerMsg::ChangeSelectionDown))?;
-                    Ok(())
+                        .send(Message::Server(ServerMsg::Select(index)))?;
                 }
-                KeyCode::Char('e') => {
+            }
+            MouseGesture::DoubleClick(position) => {
+                if let Some(index) = mouse::list_row(list, position, server_count) {
                     self.sender
-                        .send(Message::Global(GlobalMsg::SwitchToConnectionEditScreen))?;
-                    Ok(())
-                }
-                KeyCode::Enter => {
+                        .send(Message::Server(ServerMsg::Select(index)))?;
                     self.sender
                         .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
-                    Ok(())
                 }
-                _ => Ok(()),
-            },
-            _ => Ok(()),
+            }
+            MouseGesture::ScrollUp(position) if list.contains(position) => {
+                self.sender
+                    .send(Message::Server(ServerMsg::ChangeSelectionUp))?;
+            }
+            MouseGesture::ScrollDown(position) if list.contains(position) => {
+                self.sender
+                    .send(Message::Server(ServerMsg::ChangeSelectionDown))?;
+            }
+            _ => {}
         }
+        Ok(())
     }
 }
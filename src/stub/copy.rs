@@ -0,0 +1,292 @@
+use crate::wire_mock::client::StubMapping;
+use serde_json::Value;
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CopyFormat {
+    MappingJson,
+    RegisterCurl,
+    RequestCurl,
+}
+
+impl CopyFormat {
+    pub fn description(&self) -> &'static str {
+        match self {
+            CopyFormat::MappingJson => "mapping JSON",
+            CopyFormat::RegisterCurl => "curl command registering the stub",
+            CopyFormat::RequestCurl => "curl command sending a matching request",
+        }
+    }
+}
+
+pub fn render(
+    format: CopyFormat,
+    stub: &StubMapping,
+    server_url: &str,
+) -> Result<String, serde_json::Error> {
+    match format {
+        CopyFormat::MappingJson => serde_json::to_string_pretty(stub),
+        CopyFormat::RegisterCurl => Ok(format!(
+            "curl -X POST {} -H 'Content-Type: application/json' -d {}",
+            shell_quote(&format!("{}/__admin/mappings", server_url)),
+            shell_quote(&serde_json::to_string(stub)?)
+        )),
+        CopyFormat::RequestCurl => Ok(request_curl(stub, server_url)),
+    }
+}
+
+/// Builds a request the stub would match, inventing values for regex and
+/// other non literal matchers.
+fn request_curl(stub: &StubMapping, server_url: &str) -> String {
+    let request = &stub.request;
+    let method = match request.method.as_str() {
+        "ANY" => "GET",
+        method => method,
+    };
+
+    let mut url = if let Some(url) = &request.url {
+        url.clone()
+    } else if let Some(path) = &request.url_path {
+        path.clone()
+    } else if let Some(pattern) = &request.url_pattern {
+        sample_from_regex(pattern)
+    } else if let Some(pattern) = &request.url_path_pattern {
+        sample_from_regex(pattern)
+    } else {
+        "/".to_string()
+    };
+    if request.url.is_none() && request.url_pattern.is_none() {
+        let parameters = matcher_entries(request.extra.get("queryParameters"));
+        if !parameters.is_empty() {
+            let query = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(parameters)
+                .finish();
+            url = format!("{}?{}", url, query);
+        }
+    }
+
+    let mut command = format!(
+        "curl -X {} {}",
+        method,
+        shell_quote(&format!("{}{}", server_url, url))
+    );
+    for (name, value) in matcher_entries(request.extra.get("headers")) {
+        command.push_str(&format!(
+            " -H {}",
+            shell_quote(&format!("{}: {}", name, value))
+        ));
+    }
+    let body = request
+        .extra
+        .get("bodyPatterns")
+        .and_then(Value::as_array)
+        .and_then(|patterns| patterns.iter().find_map(sample_for_matcher));
+    if let Some(body) = body {
+        command.push_str(&format!(" -d {}", shell_quote(&body)));
+    }
+    command
+}
+
+/// Names and sample values of a `{"name": {matcher}}` object.
+fn matcher_entries(matchers: Option<&Value>) -> Vec<(String, String)> {
+    matchers
+        .and_then(Value::as_object)
+        .map(|matchers| {
+            matchers
+                .iter()
+                .filter_map(|(name, matcher)| {
+                    sample_for_matcher(matcher).map(|value| (name.clone(), value))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// A value satisfying a WireMock string matcher, or `None` for `absent`.
+pub fn sample_for_matcher(matcher: &Value) -> Option<String> {
+    let matcher = matcher.as_object()?;
+    if matcher.get("absent").and_then(Value::as_bool) == Some(true) {
+        return None;
+    }
+    for key in ["equalTo", "contains", "equalToXml", "binaryEqualTo"] {
+        if let Some(value) = matcher.get(key).and_then(Value::as_str) {
+            return Some(value.to_string());
+        }
+    }
+    if let Some(json) = matcher.get("equalToJson") {
+        return Some(match json {
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        });
+    }
+    if let Some(pattern) = matcher.get("matches").and_then(Value::as_str) {
+        return Some(sample_from_regex(pattern));
+    }
+    if let Some(list) = matcher.get("hasExactly").or(matcher.get("includes")) {
+        return list
+            .as_array()
+            .and_then(|l| l.first())
+            .and_then(sample_for_matcher);
+    }
+    Some("x".to_string())
+}
+
+/// Produces a string matching a regular expression by taking the first
+/// alternative of every choice and the minimum useful repetition.
+pub fn sample_from_regex(pattern: &str) -> String {
+    let mut chars = pattern.chars().peekable();
+    sample_alternation(&mut chars)
+}
+
+fn sample_alternation(chars: &mut Peekable<Chars>) -> String {
+    let sample = sample_sequence(chars);
+    // Skip the remaining alternatives of this group
+    let mut depth = 0;
+    while let Some(&c) = chars.peek() {
+        match c {
+            ')' if depth == 0 => break,
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '\\' => {
+                chars.next();
+            }
+            _ => {}
+        }
+        chars.next();
+    }
+    sample
+}
+
+fn sample_sequence(chars: &mut Peekable<Chars>) -> String {
+    let mut sample = String::new();
+    while let Some(&c) = chars.peek() {
+        if c == '|' || c == ')' {
+            break;
+        }
+        chars.next();
+        let atom = match c {
+            '(' => {
+                if chars.peek() == Some(&'?') {
+                    chars.next();
+                    // Inline flags like `(?i)` stand for no characters
+                    while chars
+                        .peek()
+                        .is_some_and(|c| c.is_ascii_alphabetic() || *c == '-')
+                    {
+                        chars.next();
+                    }
+                    if chars.peek() == Some(&')') {
+                        chars.next();
+                        continue;
+                    }
+                    // Non capturing, flagged or named group prefix
+                    for c in chars.by_ref() {
+                        if c == ':' || c == '>' {
+                            break;
+                        }
+                    }
+                }
+                let group = sample_alternation(chars);
+                chars.next();
+                group
+            }
+            '[' => sample_class(chars).to_string(),
+            '\\' => sample_escape(chars.next().unwrap_or('\\')),
+            '.' => "x".to_string(),
+            '^' | '$' => String::new(),
+            c => c.to_string(),
+        };
+        let repeat = sample_repeat(chars);
+        sample.push_str(&atom.repeat(repeat));
+    }
+    sample
+}
+
+fn sample_repeat(chars: &mut Peekable<Chars>) -> usize {
+    let repeat = match chars.peek() {
+        Some('*') | Some('+') | Some('?') => {
+            chars.next();
+            1
+        }
+        Some('{') => {
+            chars.next();
+            let mut min = String::new();
+            for c in chars.by_ref() {
+                if c == '}' {
+                    break;
+                }
+                min.push(c);
+            }
+            min.split(',')
+                .next()
+                .unwrap_or("1")
+                .trim()
+                .parse()
+                .unwrap_or(1)
+        }
+        _ => return 1,
+    };
+    // Lazy or possessive modifier
+    if matches!(chars.peek(), Some('?') | Some('+')) {
+        chars.next();
+    }
+    repeat
+}
+
+fn sample_escape(c: char) -> String {
+    match c {
+        'd' => "1",
+        'w' => "a",
+        's' => " ",
+        'D' | 'W' | 'S' => "x",
+        'b' | 'B' | 'A' | 'z' | 'Z' => "",
+        'n' => "\n",
+        't' => "\t",
+        other => return other.to_string(),
+    }
+    .to_string()
+}
+
+fn sample_class(chars: &mut Peekable<Chars>) -> char {
+    let negated = chars.peek() == Some(&'^');
+    if negated {
+        chars.next();
+    }
+    let mut ranges: Vec<(char, char)> = vec![];
+    let mut first = true;
+    while let Some(c) = chars.next() {
+        if c == ']' && !first {
+            break;
+        }
+        first = false;
+        let member = if c == '\\' {
+            sample_escape(chars.next().unwrap_or('\\'))
+                .chars()
+                .next()
+                .unwrap_or('x')
+        } else {
+            c
+        };
+        if ranges.len() > 1 && ranges.last() == Some(&('-', '-')) {
+            ranges.pop();
+            let start = ranges.pop().map(|r| r.0).unwrap_or(member);
+            ranges.push((start, member));
+        } else {
+            ranges.push((member, member));
+        }
+    }
+    if negated {
+        ['x', 'A', '1', '_', '-']
+            .into_iter()
+            .find(|c| !ranges.iter().any(|(lo, hi)| lo <= c && c <= hi))
+            .unwrap_or('~')
+    } else {
+        ranges.first().map(|r| r.0).unwrap_or('x')
+    }
+}
+
+/// Wraps a value in single quotes for POSIX shells.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
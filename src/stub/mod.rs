@@ -1,2 +1,3 @@
 pub mod stub_screen;
+pub mod copy;
 pub mod model;
\ No newline at end of file
@@ -1,21 +1,62 @@
+use crate::lint::rules;
+use crate::lint::rules::LintFinding;
 use crate::model::{Command, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::stub::copy;
+use crate::stub::copy::CopyFormat;
 use crate::stub::model::StubCommand::ReadAllStubs;
+use crate::ui::clipboard;
 use crate::wire_mock;
+use crate::wire_mock::client::{ServeEvents, StubMapping};
 use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::sync::broadcast::Sender;
 use tokio::time::interval;
 
+/// Hit counts are read again on a stub refresh once they are this old.
+const HITS_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Newest journal entries the hit counts are tallied from.
+const HITS_JOURNAL_LIMIT: usize = 1000;
+
+/// Reading the journal gives up after this long.
+const HITS_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// How often a stub served a request, according to the request journal.
+#[derive(Clone, Debug, Default)]
+pub struct StubHits {
+    pub count: usize,
+    pub last_hit: Option<String>,
+}
+
 pub struct StubModel {
     pub selected_server_url: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
     pub event_sender: Sender<Message>,
     pub command_sender: Sender<Command>,
-    pub stubs: Vec<wire_mock::client::StubMapping>,
+    pub stubs: Vec<StubMapping>,
     pub selected_stub_index: usize,
+    pub marked_stub_ids: HashSet<String>,
+    pub findings: Vec<LintFinding>,
+    pub hits: HashMap<String, StubHits>,
+    pub journal_size: usize,
+    /// Requests in the whole journal; more than `journal_size` when only the
+    /// newest were tallied.
+    pub journal_total: usize,
+    /// Server and time the hit counts were last read, or are being read.
+    hits_read: Option<(String, Instant)>,
+    reading_hits: bool,
     pub scroll_offset: usize,
+    /// Share of the width the stub list takes, in percent.
+    pub list_width_percent: u16,
+    /// Whether the divider between the list and the details is being dragged.
+    pub resizing_list: bool,
     pub refresh_task: Option<tokio::task::JoinHandle<()>>,
+    pub copy_menu_open: bool,
+    pub status_message: Option<String>,
 }
 
 #[async_trait]
@@ -30,6 +71,25 @@ impl ModelTrait<StubMsg, StubCommand> for StubModel {
                 self.select_previous_stub();
                 Ok(())
             }
+            StubMsg::Select(index) => {
+                self.selected_stub_index = index.min(self.stubs.len().saturating_sub(1));
+                self.scroll_offset = 0;
+                Ok(())
+            }
+            StubMsg::GrabDivider => {
+                self.resizing_list = true;
+                Ok(())
+            }
+            StubMsg::ResizeList(percent) => {
+                if self.resizing_list {
+                    self.list_width_percent = percent.clamp(20, 80);
+                }
+                Ok(())
+            }
+            StubMsg::ReleaseDivider => {
+                self.resizing_list = false;
+                Ok(())
+            }
             StubMsg::ScrollDetailsUp => {
                 self.scroll_details_up();
                 Ok(())
@@ -39,6 +99,10 @@ impl ModelTrait<StubMsg, StubCommand> for StubModel {
                 Ok(())
             }
             StubMsg::DeleteSelectedRequested => {
+                if self.is_read_only() {
+                    self.refuse_in_read_only("deleting stubs");
+                    return Ok(());
+                }
                 self.command_sender
                     .send(Command::Stub(StubCommand::DeleteSelectedStub))?;
                 Ok(())
@@ -47,6 +111,43 @@ impl ModelTrait<StubMsg, StubCommand> for StubModel {
                 self.command_sender.send(Command::Stub(ReadAllStubs))?;
                 Ok(())
             }
+            StubMsg::ReadHitsRequested => {
+                self.read_hits(true);
+                Ok(())
+            }
+            StubMsg::HitsRead(server_url, events) => {
+                self.reading_hits = false;
+                if self.selected_server_url.as_ref() == Some(&server_url) {
+                    self.tally_hits(events);
+                }
+                Ok(())
+            }
+            StubMsg::ToggleMarkSelected => {
+                self.toggle_mark_selected_stub();
+                Ok(())
+            }
+            StubMsg::OpenCopyMenu => {
+                self.copy_menu_open = true;
+                Ok(())
+            }
+            StubMsg::CloseCopyMenu => {
+                self.copy_menu_open = false;
+                Ok(())
+            }
+            StubMsg::CopyMenuPicked(format) => {
+                if self.copy_menu_open {
+                    self.copy_menu_open = false;
+                    self.command_sender
+                        .send(Command::Stub(StubCommand::CopySelectedStub(format)))?;
+                }
+                Ok(())
+            }
+            StubMsg::CopySelectedRequested(format) => {
+                self.copy_menu_open = false;
+                self.command_sender
+                    .send(Command::Stub(StubCommand::CopySelectedStub(format)))?;
+                Ok(())
+            }
             StubMsg::ToggleAutoRefreshStubsRequested => {
                 self.toggle_auto_refresh_stubs();
                 Ok(())
@@ -55,35 +156,176 @@ impl ModelTrait<StubMsg, StubCommand> for StubModel {
     }
 
     async fn handle_command(&mut self, command: StubCommand) -> Result<(), Box<dyn Error>> {
-        match command {
+        let result = match command {
             ReadAllStubs => self.read_all_stubs(),
             StubCommand::DeleteSelectedStub => self.delete_selected_stub(),
+            StubCommand::CopySelectedStub(format) => {
+                self.copy_selected_stub(format);
+                Ok(())
+            }
+        };
+        // An unreachable server is reported, not fatal
+        if let Err(err) = result {
+            self.status_message = Some(err.to_string());
         }
+        Ok(())
     }
 }
 
 impl StubModel {
-    pub fn new(event_sender: Sender<Message>, command_sender: Sender<Command>) -> Self {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
         Self {
             selected_server_url: None,
+            read_only_servers,
             event_sender,
             command_sender,
             stubs: vec![],
             selected_stub_index: 0,
+            marked_stub_ids: HashSet::new(),
+            findings: vec![],
+            hits: HashMap::new(),
+            journal_size: 0,
+            journal_total: 0,
+            hits_read: None,
+            reading_hits: false,
             scroll_offset: 0,
+            list_width_percent: 40,
+            resizing_list: false,
             refresh_task: None,
+            copy_menu_open: false,
+            status_message: None,
         }
     }
 
+    /// Whether the selected server must not be changed.
+    pub fn is_read_only(&self) -> bool {
+        self.selected_server_url
+            .as_ref()
+            .is_some_and(|url| self.read_only_servers.contains(url))
+    }
+
+    /// Explains why a change to the selected server was not made.
+    pub fn refuse_in_read_only(&mut self, action: &str) {
+        self.status_message = Some(format!("Read-only server: {} is disabled", action));
+    }
+
     fn read_all_stubs(&mut self) -> Result<(), Box<dyn Error>> {
         if self.selected_server_url.is_none() {
             return Err(Box::new(StubError::NoServerSelected));
         }
-        let res = wire_mock::client::get_all_stubs(&self.selected_server_url.as_ref().unwrap())?;
+        let res = wire_mock::client::get_all_stubs(self.selected_server_url.as_ref().unwrap())?;
         self.stubs = res.mappings;
+        let ids: HashSet<&String> = self.stubs.iter().map(|s| &s.id).collect();
+        self.marked_stub_ids.retain(|id| ids.contains(id));
+        self.findings = rules::lint_stubs(&self.stubs);
+        self.read_hits(false);
         Ok(())
     }
 
+    /// Reads the newest journal entries in the background to count stub
+    /// hits, unless they were read lately and `force` is not set.
+    fn read_hits(&mut self, force: bool) {
+        let Some(server_url) = self.selected_server_url.clone() else {
+            return;
+        };
+        let same_server = self
+            .hits_read
+            .as_ref()
+            .is_some_and(|(read_from, _)| *read_from == server_url);
+        if !same_server {
+            self.hits.clear();
+            self.journal_size = 0;
+            self.journal_total = 0;
+        }
+        let fresh = same_server
+            && self
+                .hits_read
+                .as_ref()
+                .is_some_and(|(_, read_at)| read_at.elapsed() < HITS_REFRESH_INTERVAL);
+        if self.reading_hits || (fresh && !force) {
+            return;
+        }
+        self.reading_hits = true;
+        self.hits_read = Some((server_url.clone(), Instant::now()));
+        let sender = self.event_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let events = wire_mock::client::get_recent_requests(
+                &server_url,
+                HITS_JOURNAL_LIMIT,
+                HITS_TIMEOUT,
+            )
+            .map_err(|err| err.to_string());
+            let _ = sender.send(Message::Stub(StubMsg::HitsRead(server_url, events)));
+        });
+    }
+
+    /// Tallies journal entries per stub. The journal may be disabled on the
+    /// server, so failing to read it only leaves the counts empty.
+    fn tally_hits(&mut self, events: Result<ServeEvents, String>) {
+        let Ok(ServeEvents {
+            requests: events,
+            meta,
+        }) = events
+        else {
+            self.hits.clear();
+            self.journal_size = 0;
+            self.journal_total = 0;
+            return;
+        };
+        let mut hits: HashMap<String, StubHits> = HashMap::new();
+        // The journal lists the newest request first
+        for event in &events {
+            if let Some(id) = event.matched_stub_id() {
+                let entry = hits.entry(id.to_string()).or_default();
+                entry.count += 1;
+                if entry.last_hit.is_none() {
+                    entry.last_hit = event.request.logged_date_string.clone();
+                }
+            }
+        }
+        self.hits = hits;
+        self.journal_size = events.len();
+        self.journal_total = meta.map_or(events.len(), |meta| meta.total);
+    }
+
+    pub fn hit_count(&self, stub: &StubMapping) -> usize {
+        self.hits.get(&stub.id).map_or(0, |h| h.count)
+    }
+
+    /// Lint findings for the stub at `index`.
+    pub fn findings_for(&self, index: usize) -> impl Iterator<Item = &LintFinding> {
+        self.findings.iter().filter(move |f| f.stub_index == index)
+    }
+
+    /// The marked stubs, or the selected one when nothing is marked.
+    pub fn marked_or_selected_stubs(&self) -> Vec<StubMapping> {
+        if self.marked_stub_ids.is_empty() {
+            return self
+                .stubs
+                .get(self.selected_stub_index)
+                .cloned()
+                .into_iter()
+                .collect();
+        }
+        self.stubs
+            .iter()
+            .filter(|s| self.marked_stub_ids.contains(&s.id))
+            .cloned()
+            .collect()
+    }
+
+    fn toggle_mark_selected_stub(&mut self) {
+        if let Some(stub) = self.stubs.get(self.selected_stub_index)
+            && !self.marked_stub_ids.remove(&stub.id)
+        {
+            self.marked_stub_ids.insert(stub.id.clone());
+        }
+    }
+
     fn select_next_stub(&mut self) {
         if self.stubs.is_empty() {
             return;
@@ -127,17 +369,39 @@ impl StubModel {
         self.refresh_task = Some(task);
     }
 
+    fn copy_selected_stub(&mut self, format: CopyFormat) {
+        let (Some(stub), Some(server_url)) = (
+            self.stubs.get(self.selected_stub_index),
+            self.selected_server_url.as_ref(),
+        ) else {
+            return;
+        };
+        let result = copy::render(format, stub, server_url)
+            .map_err(|e| e.to_string())
+            .and_then(|text| clipboard::copy_to_clipboard(&text).map_err(|e| e.to_string()));
+        self.status_message = Some(match result {
+            Ok(()) => format!("Copied {} to the clipboard", format.description()),
+            Err(err) => format!("Copy failed: {}", err),
+        });
+    }
+
     fn delete_selected_stub(&mut self) -> Result<(), Box<dyn Error>> {
-        if self.stubs.is_empty() || self.selected_server_url.is_none() {
+        let Some(server_url) = self.selected_server_url.clone() else {
+            return Ok(());
+        };
+        self.read_only_servers
+            .check(&server_url, "deleting stubs")?;
+        if self.stubs.is_empty() {
             return Ok(());
         }
         let idx = self.selected_stub_index.min(self.stubs.len() - 1);
         if let Some(stub) = self.stubs.get(idx) {
             let id = stub.id.clone();
             // Perform delete on server
-            wire_mock::client::delete_stub(self.selected_server_url.as_ref().unwrap(), &id)?;
+            wire_mock::client::delete_stub(&server_url, &id)?;
             // Remove locally
             self.stubs.remove(idx);
+            self.findings = rules::lint_stubs(&self.stubs);
             // Adjust selection
             if self.stubs.is_empty() {
                 self.selected_stub_index = 0;
@@ -157,17 +421,32 @@ impl StubModel {
 pub enum StubCommand {
     ReadAllStubs,
     DeleteSelectedStub,
+    CopySelectedStub(CopyFormat),
 }
 
 #[derive(Clone, Debug)]
 pub enum StubMsg {
     SelectNext,
     SelectPrevious,
+    Select(usize),
     ScrollDetailsUp,
     ScrollDetailsDown,
+    GrabDivider,
+    ResizeList(u16),
+    ReleaseDivider,
+    ToggleMarkSelected,
+    OpenCopyMenu,
+    CloseCopyMenu,
+    /// A format picked in the copy menu; ignored once the menu is closed.
+    CopyMenuPicked(CopyFormat),
+    CopySelectedRequested(CopyFormat),
     ToggleAutoRefreshStubsRequested,
     DeleteSelectedRequested,
     ReadAllStubsRequested,
+    ReadHitsRequested,
+    /// The newest journal entries of the server, or why they could not be
+    /// read.
+    HitsRead(String, Result<ServeEvents, String>),
 }
 
 #[derive(Error, Debug)]
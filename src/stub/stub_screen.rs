@@ -1,12 +1,18 @@
+use crate::alerts::model::AlertMsg;
+use crate::keymap::action::{Action, Scope};
+use crate::lint::rules::Severity;
 use crate::model::ScreenTrait;
-use crate::model::{ApplicationModel, Message};
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::stub::copy::CopyFormat;
 use crate::stub::model::StubMsg;
+use crate::transfer::model::TransferMode;
 use crate::ui;
+use crate::ui::mouse::{self, MouseGesture};
 use async_trait::async_trait;
 use crossterm::event::{Event, KeyCode};
-use ratatui::layout::{Constraint, Direction, Layout};
-use ratatui::prelude::{Color, Modifier, Style};
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use ratatui::prelude::{Modifier, Style};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
 use ratatui::Frame;
 use sync::broadcast::Sender;
 use tokio::sync;
@@ -20,43 +26,87 @@ impl StubScreen {
         StubScreen { sender }
     }
 
+    /// The areas of the title, stub list, details, status and commands.
+    fn layout(&self, app: &ApplicationModel, area: Rect) -> [Rect; 5] {
+        let [title, content, status, commands] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(3),
+        ])
+        .areas(area);
+        let list_width = app.stub_model.list_width_percent;
+        let [list, details] = Layout::horizontal([
+            Constraint::Percentage(list_width),
+            Constraint::Percentage(100 - list_width),
+        ])
+        .areas(content);
+        [title, list, details, status, commands]
+    }
+
     fn get_stub_details(&self, app: &ApplicationModel) -> String {
         if app.stub_model.stubs.is_empty() {
             return "No stubs available".to_string();
         }
 
-        let stub = &app.stub_model.stubs[app.stub_model.selected_stub_index];
+        let index = app.stub_model.selected_stub_index;
+        let stub = &app.stub_model.stubs[index];
 
         // Format as JSON for readability
-        match serde_json::to_string_pretty(&stub) {
+        let details = match serde_json::to_string_pretty(&stub) {
             Ok(json) => json,
             Err(_) => format!("{:#?}", stub), // Fallback to Debug format
+        };
+        let hits = match app.stub_model.hits.get(&stub.id) {
+            Some(hits) => format!(
+                "Hits: {}, last at {}",
+                hits.count,
+                hits.last_hit.as_deref().unwrap_or("unknown time")
+            ),
+            None => "Hits: never".to_string(),
+        };
+        let findings: Vec<String> = app
+            .stub_model
+            .findings_for(index)
+            .map(|f| format!("⚠ {}: {}", f.kind.label(), f.message))
+            .collect();
+        if findings.is_empty() {
+            format!("{}\n\n{}", hits, details)
+        } else {
+            format!("{}\n{}\n\n{}", hits, findings.join("\n"), details)
         }
     }
 }
 
+/// Actions that change the selected server.
+const MUTATING_ACTIONS: [Action; 7] = [
+    Action::Delete,
+    Action::MoveToServer,
+    Action::Housekeeping,
+    Action::Import,
+    Action::FromOpenApi,
+    Action::FromHar,
+    Action::WatchDirectory,
+];
+
 #[async_trait]
 impl ScreenTrait for StubScreen {
+    fn scope(&self) -> Scope {
+        Scope::Stubs
+    }
+
     fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
-        let main_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints(vec![
-                Constraint::Length(3),
-                Constraint::Min(0),
-                Constraint::Length(3),
-            ])
-            .split(frame.area());
+        let [title_area, list_area, details_area, status_area, commands_area] =
+            self.layout(app, frame.area());
 
         // Title
-        let title = ui::widgets::title_paragraph("Wire Mock Inspector - Stub Mappings");
-
-        frame.render_widget(title, main_layout[0]);
+        let title = ui::widgets::server_title_paragraph(
+            "Wire Mock Inspector - Stub Mappings",
+            app.stub_model.is_read_only(),
+            &app.theme,
+        );
 
-        // Split middle area into two columns
-        let content_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
-            .split(main_layout[1]);
+        frame.render_widget(title, title_area);
 
         // Stubs list display (left side)
         let items: Vec<ListItem> = app
@@ -65,24 +115,51 @@ impl ScreenTrait for StubScreen {
             .iter()
             .enumerate()
             .map(|(i, stub)| {
-                let url = stub
-                    .request
-                    .url
-                    .as_ref()
-                    .map(|s| s.as_str())
-                    .unwrap_or("(no url)");
+                let url = stub.request.display_url().unwrap_or("(no url)");
+                let mark = if app.stub_model.marked_stub_ids.contains(&stub.id) {
+                    "●"
+                } else {
+                    " "
+                };
+
+                let severity = app
+                    .stub_model
+                    .findings_for(i)
+                    .map(|f| f.kind.severity())
+                    .max();
+                let badge = match severity {
+                    Some(Severity::Error) => "✘",
+                    Some(Severity::Warning) => "⚠",
+                    None => " ",
+                };
 
                 let (text, style) = if i == app.stub_model.selected_stub_index {
                     (
-                        format!("▶ {} {}", stub.request.method, url),
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
+                        format!(
+                            "▶{}{} {} {} ({}×)",
+                            mark,
+                            badge,
+                            stub.request.method,
+                            url,
+                            app.stub_model.hit_count(stub)
+                        ),
+                        app.theme.selected(),
                     )
                 } else {
                     (
-                        format!("  {} {}", stub.request.method, url),
-                        Style::default().fg(Color::White),
+                        format!(
+                            " {}{} {} {} ({}×)",
+                            mark,
+                            badge,
+                            stub.request.method,
+                            url,
+                            app.stub_model.hit_count(stub)
+                        ),
+                        match severity {
+                            Some(Severity::Error) => app.theme.error(),
+                            Some(Severity::Warning) => app.theme.warning(),
+                            None => app.theme.text(),
+                        },
                     )
                 };
 
@@ -96,86 +173,279 @@ impl ScreenTrait for StubScreen {
                 .title("Stub Mappings"),
         );
 
-        frame.render_widget(stubs_list, content_layout[0]);
+        frame.render_widget(stubs_list, list_area);
 
         // Details view (right side)
         let details = self.get_stub_details(app);
 
         let details_paragraph = Paragraph::new(details)
             .block(Block::default().borders(Borders::ALL).title("Details"))
-            .style(Style::default().fg(Color::White))
+            .style(app.theme.text())
             .wrap(Wrap { trim: false })
             .scroll((app.stub_model.scroll_offset as u16, 0));
 
-        frame.render_widget(details_paragraph, content_layout[1]);
-
-        // Commands
-        let commands = vec![
-            "↑/k: Up",
-            "↓/j: Down",
-            "PgUp/PgDn: Scroll",
-            "r: Refresh",
-            "d: Delete",
-            "q: Quit",
-            "a: Toggle auto refresh",
-        ];
-
-        let control_layout = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints(vec![
-                Constraint::Ratio(1, commands.len() as u32);
-                commands.len()
-            ])
-            .split(main_layout[2]);
-
-        for (index, command) in commands.iter().enumerate() {
-            let paragraph = Paragraph::new(*command);
-            frame.render_widget(paragraph, control_layout[index]);
+        frame.render_widget(details_paragraph, details_area);
+
+        // Status bar
+        let status_style = if app.alert_model.new_unmatched > 0 {
+            app.theme.error().add_modifier(Modifier::BOLD)
+        } else if app.sync_model.conflicts.is_empty() {
+            Style::default()
+        } else {
+            app.theme.error()
+        };
+        let status_text = [
+            app.alert_model.status_text(),
+            app.stub_model.status_message.clone(),
+            app.alert_model.status_message.clone(),
+            app.sync_model.status_text(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" │ ");
+        let status = Paragraph::new(status_text).style(status_style);
+        frame.render_widget(status, status_area);
+
+        // Commands that change the server are hidden when it is read-only
+        let hidden: &[Action] = if app.stub_model.is_read_only() {
+            &MUTATING_ACTIONS
+        } else {
+            &[]
+        };
+        let commands = app.keymap.commands(Scope::Stubs, hidden);
+        ui::widgets::render_commands(frame, commands_area, &commands);
+
+        // Copy format menu
+        if app.stub_model.copy_menu_open {
+            let area = ui::widgets::centered_rect(60, 7, frame.area());
+            let formats = [
+                CopyFormat::MappingJson,
+                CopyFormat::RegisterCurl,
+                CopyFormat::RequestCurl,
+            ];
+            let mut lines: Vec<String> = formats
+                .iter()
+                .enumerate()
+                .map(|(i, format)| format!("{}: {}", i + 1, format.description()))
+                .collect();
+            lines.push("Esc: Cancel".to_string());
+            let menu = Paragraph::new(lines.join("\n"))
+                .block(Block::default().borders(Borders::ALL).title("Copy as"));
+            frame.render_widget(Clear, area);
+            frame.render_widget(menu, area);
+        }
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender.send(Message::Stub(StubMsg::CloseCopyMenu))?;
+                Ok(())
+            }
+            Action::AutoRefresh => {
+                self.sender
+                    .send(Message::Stub(StubMsg::ToggleAutoRefreshStubsRequested))?;
+                Ok(())
+            }
+            Action::Refresh => {
+                self.sender
+                    .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+                Ok(())
+            }
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Delete => {
+                self.sender
+                    .send(Message::Stub(StubMsg::DeleteSelectedRequested))?;
+                Ok(())
+            }
+            Action::Mark => {
+                self.sender
+                    .send(Message::Stub(StubMsg::ToggleMarkSelected))?;
+                Ok(())
+            }
+            Action::CopyToServer => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToTransferScreen(
+                        TransferMode::Copy,
+                    )))?;
+                Ok(())
+            }
+            Action::MoveToServer => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToTransferScreen(
+                        TransferMode::Move,
+                    )))?;
+                Ok(())
+            }
+            Action::WatchDirectory => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToSyncScreen))?;
+                Ok(())
+            }
+            Action::CopyAs => {
+                self.sender.send(Message::Stub(StubMsg::OpenCopyMenu))?;
+                Ok(())
+            }
+            Action::RequestJournal => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToJournalScreen))?;
+                Ok(())
+            }
+            Action::LintReport => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToLintScreen))?;
+                Ok(())
+            }
+            Action::Housekeeping => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToAdminScreen))?;
+                Ok(())
+            }
+            Action::AlertMode => {
+                self.sender
+                    .send(Message::Alert(AlertMsg::CycleDesktopNotification))?;
+                Ok(())
+            }
+            Action::LiveTraffic => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToDashboardScreen))?;
+                Ok(())
+            }
+            Action::Coverage => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToCoverageScreen))?;
+                Ok(())
+            }
+            Action::TestRequest => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToTesterScreen))?;
+                Ok(())
+            }
+            Action::FromHar => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToHarScreen))?;
+                Ok(())
+            }
+            Action::FromOpenApi => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToOpenApiScreen))?;
+                Ok(())
+            }
+            Action::Import => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToImportScreen))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender.send(Message::Stub(StubMsg::SelectPrevious))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender.send(Message::Stub(StubMsg::SelectNext))?;
+                Ok(())
+            }
+            Action::ScrollUp => {
+                self.sender.send(Message::Stub(StubMsg::ScrollDetailsUp))?;
+                Ok(())
+            }
+            Action::ScrollDown => {
+                self.sender
+                    .send(Message::Stub(StubMsg::ScrollDetailsDown))?;
+                Ok(())
+            }
+            _ => Ok(()),
         }
     }
 
+    /// While the copy menu is open, it closes with Back and nothing else runs.
+    fn takes_action(&self, app: &ApplicationModel, action: Action) -> bool {
+        !app.stub_model.copy_menu_open || action == Action::Back
+    }
+
+    /// The copy menu picks its format with digits.
     async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
-        return match event {
+        match event {
             Event::Key(key) => match key.code {
-                KeyCode::Char('a') => {
-                    self.sender
-                        .send(Message::Stub(StubMsg::ToggleAutoRefreshStubsRequested))?;
-                    Ok(())
-                }
-                KeyCode::Char('r') => {
+                KeyCode::Char(c @ '1'..='3') => {
+                    let format = match c {
+                        '1' => CopyFormat::MappingJson,
+                        '2' => CopyFormat::RegisterCurl,
+                        _ => CopyFormat::RequestCurl,
+                    };
                     self.sender
-                        .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
-                    Ok(())
-                }
-                KeyCode::Char('q') => {
-                    self.sender.send(Message::QuitRequested)?;
-                    Ok(())
-                }
-                KeyCode::Char('d') => {
-                    self.sender
-                        .send(Message::Stub(StubMsg::DeleteSelectedRequested))?;
-                    Ok(())
-                }
-                KeyCode::Up | KeyCode::Char('k') => {
-                    self.sender.send(Message::Stub(StubMsg::SelectPrevious))?;
-                    Ok(())
-                }
-                KeyCode::Down | KeyCode::Char('j') => {
-                    self.sender.send(Message::Stub(StubMsg::SelectNext))?;
-                    Ok(())
-                }
-                KeyCode::PageUp => {
-                    self.sender.send(Message::Stub(StubMsg::ScrollDetailsUp))?;
-                    Ok(())
-                }
-                KeyCode::PageDown => {
-                    self.sender
-                        .send(Message::Stub(StubMsg::ScrollDetailsDown))?;
+                        .send(Message::Stub(StubMsg::CopyMenuPicked(format)))?;
                     Ok(())
                 }
                 _ => Ok(()),
             },
             _ => Ok(()),
+        }
+    }
+
+    /// Clicks select a stub and a double-click opens its copy menu. The wheel
+    /// moves through the list or scrolls the details, whichever is under the
+    /// pointer, and dragging the divider between them resizes the list.
+    fn handle_mouse(
+        &self,
+        app: &ApplicationModel,
+        area: Rect,
+        gesture: MouseGesture,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if app.stub_model.copy_menu_open {
+            return Ok(());
+        }
+        let [_, list, details, _, _] = self.layout(app, area);
+        let stub_count = app.stub_model.stubs.len();
+        let on_divider = |position: Position| {
+            (list.right().saturating_sub(1)..=details.x).contains(&position.x)
+                && (list.y..list.bottom()).contains(&position.y)
         };
+        match gesture {
+            MouseGesture::Click(position) | MouseGesture::DoubleClick(position)
+                if on_divider(position) =>
+            {
+                self.sender.send(Message::Stub(StubMsg::GrabDivider))?;
+            }
+            MouseGesture::Click(position) => {
+                if let Some(index) = mouse::list_row(list, position, stub_count) {
+                    self.sender.send(Message::Stub(StubMsg::Select(index)))?;
+                }
+            }
+            MouseGesture::DoubleClick(position) => {
+                if let Some(index) = mouse::list_row(list, position, stub_count) {
+                    self.sender.send(Message::Stub(StubMsg::Select(index)))?;
+                    self.sender.send(Message::Stub(StubMsg::OpenCopyMenu))?;
+                }
+            }
+            MouseGesture::Drag(position) if app.stub_model.resizing_list => {
+                let width = (list.width + details.width).max(1) as u32;
+                let list_width = position.x.saturating_sub(list.x) as u32 + 1;
+                let percent = (list_width * 100 / width) as u16;
+                self.sender
+                    .send(Message::Stub(StubMsg::ResizeList(percent)))?;
+            }
+            MouseGesture::Release(_) if app.stub_model.resizing_list => {
+                self.sender.send(Message::Stub(StubMsg::ReleaseDivider))?;
+            }
+            MouseGesture::ScrollUp(position) if list.contains(position) => {
+                self.sender.send(Message::Stub(StubMsg::SelectPrevious))?;
+            }
+            MouseGesture::ScrollDown(position) if list.contains(position) => {
+                self.sender.send(Message::Stub(StubMsg::SelectNext))?;
+            }
+            MouseGesture::ScrollUp(position) if details.contains(position) => {
+                self.sender.send(Message::Stub(StubMsg::ScrollDetailsUp))?;
+            }
+            MouseGesture::ScrollDown(position) if details.contains(position) => {
+                self.sender
+                    .send(Message::Stub(StubMsg::ScrollDetailsDown))?;
+            }
+            _ => {}
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,410 @@
+use crate::import::files;
+use crate::model::{Command, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::stub::model::StubMsg;
+use crate::wire_mock;
+use crate::wire_mock::client::StubMapping;
+use async_trait::async_trait;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+/// Local mapping as it was last pushed to the server.
+#[derive(Clone, Debug)]
+struct SyncedStub {
+    source: PathBuf,
+    server_id: String,
+    mapping: StubMapping,
+}
+
+#[derive(Clone, Debug)]
+pub struct SyncConflict {
+    pub key: String,
+    pub reason: String,
+}
+
+pub struct SyncModel {
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub server_url: Option<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub path_input: String,
+    pub watched_dir: Option<PathBuf>,
+    pub conflicts: Vec<SyncConflict>,
+    pub log: Vec<String>,
+    pub last_sync: Option<Instant>,
+    pub last_error: Option<String>,
+    synced: HashMap<String, SyncedStub>,
+    watcher: Option<RecommendedWatcher>,
+    resync_pending: bool,
+}
+
+#[async_trait]
+impl ModelTrait<SyncMsg, SyncCommand> for SyncModel {
+    async fn apply_event(&mut self, event: SyncMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            SyncMsg::InputChar(c) => {
+                self.path_input.push(c);
+                Ok(())
+            }
+            SyncMsg::InputBackspace => {
+                self.path_input.pop();
+                Ok(())
+            }
+            SyncMsg::ToggleWatchRequested => {
+                if self.watcher.is_some() {
+                    self.stop();
+                } else {
+                    self.command_sender
+                        .send(Command::Sync(SyncCommand::StartWatching))?;
+                }
+                Ok(())
+            }
+            SyncMsg::FilesChanged => {
+                // Editors emit bursts of events, so wait for them to settle.
+                if !self.resync_pending && self.watcher.is_some() {
+                    self.resync_pending = true;
+                    let sender = self.event_sender.clone();
+                    tokio::spawn(async move {
+                        tokio::time::sleep(Duration::from_millis(300)).await;
+                        let _ = sender.send(Message::Sync(SyncMsg::ResyncRequested));
+                    });
+                }
+                Ok(())
+            }
+            SyncMsg::ResyncRequested => {
+                self.resync_pending = false;
+                self.command_sender
+                    .send(Command::Sync(SyncCommand::Sync { force: false }))?;
+                Ok(())
+            }
+            SyncMsg::ForcePushRequested => {
+                self.command_sender
+                    .send(Command::Sync(SyncCommand::Sync { force: true }))?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: SyncCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            SyncCommand::StartWatching => self.start(),
+            SyncCommand::Sync { force } => self.sync(force),
+        };
+        if let Err(err) = result {
+            self.last_error = Some(err.to_string());
+            self.log(format!("Error: {}", err));
+        }
+        Ok(())
+    }
+}
+
+impl SyncModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            event_sender,
+            command_sender,
+            server_url: None,
+            read_only_servers,
+            path_input: String::new(),
+            watched_dir: None,
+            conflicts: vec![],
+            log: vec![],
+            last_sync: None,
+            last_error: None,
+            synced: HashMap::new(),
+            watcher: None,
+            resync_pending: false,
+        }
+    }
+
+    pub fn is_watching(&self) -> bool {
+        self.watcher.is_some()
+    }
+
+    /// One line summary of the sync state for the status bar.
+    pub fn status_text(&self) -> Option<String> {
+        let dir = self.watched_dir.as_ref()?;
+        let mut text = format!(
+            "Sync {} → {}: {} stubs",
+            dir.display(),
+            self.server_url.as_deref().unwrap_or("?"),
+            self.synced.len()
+        );
+        if let Some(last_sync) = self.last_sync {
+            text.push_str(&format!(", synced {}s ago", last_sync.elapsed().as_secs()));
+        }
+        if !self.conflicts.is_empty() {
+            text.push_str(&format!(", {} CONFLICTS", self.conflicts.len()));
+        }
+        if let Some(error) = &self.last_error {
+            text.push_str(&format!(", error: {}", error));
+        }
+        Some(text)
+    }
+
+    fn start(&mut self) -> Result<(), Box<dyn Error>> {
+        let Some(server_url) = &self.server_url else {
+            return Err(Box::new(SyncError::NoServerSelected));
+        };
+        self.read_only_servers
+            .check(server_url, "syncing a directory")?;
+        let dir = PathBuf::from(self.path_input.trim());
+        if !dir.is_dir() {
+            return Err(Box::new(SyncError::NotADirectory(
+                dir.display().to_string(),
+            )));
+        }
+
+        let sender = self.event_sender.clone();
+        let mut watcher =
+            notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+                if res.is_ok_and(|event| !event.kind.is_access()) {
+                    let _ = sender.send(Message::Sync(SyncMsg::FilesChanged));
+                }
+            })?;
+        watcher.watch(&dir, RecursiveMode::Recursive)?;
+
+        self.synced.clear();
+        self.conflicts.clear();
+        self.last_error = None;
+        self.watched_dir = Some(dir.clone());
+        self.watcher = Some(watcher);
+        self.log(format!("Watching {}", dir.display()));
+        self.sync(false)
+    }
+
+    fn stop(&mut self) {
+        self.watcher = None;
+        if let Some(dir) = self.watched_dir.take() {
+            self.log(format!("Stopped watching {}", dir.display()));
+        }
+        self.synced.clear();
+        self.conflicts.clear();
+        self.last_error = None;
+    }
+
+    /// Pushes every local change since the last sync. Without `force`, stubs
+    /// whose server copy no longer matches what was pushed are left alone and
+    /// reported as conflicts. A stub that fails to sync is logged and retried
+    /// on the next pass, without holding up the others.
+    fn sync(&mut self, force: bool) -> Result<(), Box<dyn Error>> {
+        let (Some(dir), Some(server_url)) = (self.watched_dir.clone(), self.server_url.clone())
+        else {
+            return Ok(());
+        };
+        self.read_only_servers
+            .check(&server_url, "syncing a directory")?;
+        let (local, unreadable) = read_local_mappings(&dir)?;
+        let mut errors: Vec<String> = unreadable.iter().map(|(_, error)| error.clone()).collect();
+
+        self.conflicts.clear();
+        let mut changed = 0;
+
+        // Created and updated stubs
+        for (key, (source, mapping)) in local.iter() {
+            let previous = self.synced.get(key).cloned();
+            if previous.as_ref().is_some_and(|p| &p.mapping == mapping) {
+                continue;
+            }
+            if let Some(previous) = &previous
+                && !force
+            {
+                match self.server_conflict(&server_url, previous) {
+                    Ok(None) => {}
+                    Ok(Some(reason)) => {
+                        self.conflicts.push(SyncConflict {
+                            key: key.clone(),
+                            reason,
+                        });
+                        continue;
+                    }
+                    Err(err) => {
+                        errors.push(format!("Failed to push {}: {}", key, err));
+                        continue;
+                    }
+                }
+            }
+            let server_id = match self.push(&server_url, previous.as_ref(), mapping) {
+                Ok(server_id) => server_id,
+                Err(err) => {
+                    errors.push(format!("Failed to push {}: {}", key, err));
+                    continue;
+                }
+            };
+            self.log(format!("Pushed {} from {}", key, source.display()));
+            self.synced.insert(
+                key.clone(),
+                SyncedStub {
+                    source: source.clone(),
+                    server_id,
+                    mapping: mapping.clone(),
+                },
+            );
+            changed += 1;
+        }
+
+        // Deleted stubs, unless their file just failed to parse
+        let removed: Vec<String> = self
+            .synced
+            .iter()
+            .filter(|(key, synced)| {
+                !local.contains_key(*key)
+                    && !unreadable.iter().any(|(path, _)| path == &synced.source)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in removed {
+            let synced = self.synced[&key].clone();
+            if !force {
+                match self.server_conflict(&server_url, &synced) {
+                    Ok(None) => {}
+                    Ok(Some(reason)) => {
+                        self.conflicts.push(SyncConflict { key, reason });
+                        continue;
+                    }
+                    Err(err) => {
+                        errors.push(format!("Failed to delete {}: {}", key, err));
+                        continue;
+                    }
+                }
+            }
+            if let Err(err) = self.delete(&server_url, &synced) {
+                errors.push(format!("Failed to delete {}: {}", key, err));
+                continue;
+            }
+            self.log(format!("Deleted {}", key));
+            self.synced.remove(&key);
+            changed += 1;
+        }
+
+        for conflict in self.conflicts.clone() {
+            self.log(format!("Conflict on {}: {}", conflict.key, conflict.reason));
+        }
+        for error in errors.iter() {
+            self.log(error.clone());
+        }
+        self.last_error = match errors.len() {
+            0 => None,
+            1 => Some(errors[0].clone()),
+            n => Some(format!("{} (and {} more, see the log)", errors[0], n - 1)),
+        };
+        self.last_sync = Some(Instant::now());
+        if changed > 0 {
+            self.event_sender
+                .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+        }
+        Ok(())
+    }
+
+    /// Describes how the server copy differs from what was last pushed, if it does.
+    fn server_conflict(
+        &self,
+        server_url: &str,
+        synced: &SyncedStub,
+    ) -> Result<Option<String>, Box<dyn Error>> {
+        Ok(
+            match wire_mock::client::get_stub(server_url, &synced.server_id)? {
+                None => Some("deleted on the server since the last sync".to_string()),
+                Some(server) if !server.same_definition(&synced.mapping) => {
+                    Some("changed on the server since the last sync".to_string())
+                }
+                Some(_) => None,
+            },
+        )
+    }
+
+    fn push(
+        &self,
+        server_url: &str,
+        previous: Option<&SyncedStub>,
+        mapping: &StubMapping,
+    ) -> Result<String, Box<dyn Error>> {
+        let server_id = previous
+            .map(|p| p.server_id.clone())
+            .unwrap_or_else(|| mapping.id.clone());
+        if !server_id.is_empty() && wire_mock::client::get_stub(server_url, &server_id)?.is_some() {
+            let mut mapping = mapping.clone();
+            mapping.id = server_id.clone();
+            wire_mock::client::update_stub(server_url, &server_id, &mapping)?;
+            Ok(server_id)
+        } else {
+            Ok(wire_mock::client::create_stub(server_url, mapping)?.id)
+        }
+    }
+
+    fn delete(&self, server_url: &str, synced: &SyncedStub) -> Result<(), Box<dyn Error>> {
+        if wire_mock::client::get_stub(server_url, &synced.server_id)?.is_some() {
+            wire_mock::client::delete_stub(server_url, &synced.server_id)?;
+        }
+        Ok(())
+    }
+
+    fn log(&mut self, line: String) {
+        self.log.push(line);
+        if self.log.len() > 200 {
+            self.log.remove(0);
+        }
+    }
+}
+
+type LocalMappings = HashMap<String, (PathBuf, StubMapping)>;
+type UnreadableFiles = Vec<(PathBuf, String)>;
+
+/// Reads every mapping below `dir`, keyed by id or, for mappings without
+/// one, by file and position. Files that cannot be parsed are reported
+/// separately so a half saved file does not delete its stubs.
+fn read_local_mappings(dir: &Path) -> Result<(LocalMappings, UnreadableFiles), Box<dyn Error>> {
+    let mut paths = vec![];
+    files::collect_json_files(dir, &mut paths)?;
+    let mut mappings = HashMap::new();
+    let mut unreadable = vec![];
+    for path in paths {
+        match files::read_mapping_file(&path) {
+            Ok(file_mappings) => {
+                for (index, mapping) in file_mappings.into_iter().enumerate() {
+                    let key = if mapping.id.is_empty() {
+                        format!("{}#{}", path.display(), index)
+                    } else {
+                        mapping.id.clone()
+                    };
+                    mappings.insert(key, (path.clone(), mapping));
+                }
+            }
+            Err(err) => unreadable.push((path, err.to_string())),
+        }
+    }
+    Ok((mappings, unreadable))
+}
+
+#[derive(Clone, Debug)]
+pub enum SyncCommand {
+    StartWatching,
+    Sync { force: bool },
+}
+
+#[derive(Clone, Debug)]
+pub enum SyncMsg {
+    InputChar(char),
+    InputBackspace,
+    ToggleWatchRequested,
+    FilesChanged,
+    ResyncRequested,
+    ForcePushRequested,
+}
+
+#[derive(Error, Debug)]
+pub enum SyncError {
+    #[error("No server selected")]
+    NoServerSelected,
+    #[error("{0} is not a directory")]
+    NotADirectory(String),
+}
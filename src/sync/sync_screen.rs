@@ -0,0 +1,133 @@
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::sync::model::SyncMsg;
+use crate::ui;
+use async_trait::async_trait;
+use crossterm::event::{Event, KeyCode};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct SyncScreen {
+    sender: Sender<Message>,
+}
+
+impl SyncScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        SyncScreen { sender }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for SyncScreen {
+    fn scope(&self) -> Scope {
+        Scope::Sync
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.sync_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock - Sync Mappings Directory", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        // Directory input, locked while watching
+        let input_text = match &model.watched_dir {
+            Some(dir) => format!("{} (watching)", dir.display()),
+            None => format!("{}█", model.path_input),
+        };
+        let input = Paragraph::new(input_text).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Mappings directory"),
+        );
+        frame.render_widget(input, main_layout[1]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(main_layout[2]);
+
+        // Sync log (left side), newest entries at the bottom
+        let visible = content_layout[0].height.saturating_sub(2) as usize;
+        let log_items: Vec<ListItem> = model
+            .log
+            .iter()
+            .skip(model.log.len().saturating_sub(visible))
+            .map(|line| ListItem::new(line.as_str()))
+            .collect();
+        let log = List::new(log_items).block(Block::default().borders(Borders::ALL).title("Log"));
+        frame.render_widget(log, content_layout[0]);
+
+        // Conflicts (right side)
+        let conflict_items: Vec<ListItem> = model
+            .conflicts
+            .iter()
+            .map(|conflict| {
+                ListItem::new(format!("{}: {}", conflict.key, conflict.reason))
+                    .style(app.theme.error())
+            })
+            .collect();
+        let conflicts = List::new(conflict_items)
+            .block(Block::default().borders(Borders::ALL).title("Conflicts"));
+        frame.render_widget(conflicts, content_layout[1]);
+
+        // Status
+        let status = Paragraph::new(model.status_text().unwrap_or_default());
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Sync, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::ForcePush => {
+                self.sender
+                    .send(Message::Sync(SyncMsg::ForcePushRequested))?;
+                Ok(())
+            }
+            Action::ToggleWatch => {
+                self.sender
+                    .send(Message::Sync(SyncMsg::ToggleWatchRequested))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(c) => {
+                    self.sender.send(Message::Sync(SyncMsg::InputChar(c)))?;
+                    Ok(())
+                }
+                KeyCode::Backspace => {
+                    self.sender.send(Message::Sync(SyncMsg::InputBackspace))?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,342 @@
+use crate::model::{Command, Message, ModelTrait};
+use crate::wire_mock;
+use crate::wire_mock::client::{NearMiss, StubMapping, TestResponse};
+use crate::wire_mock::matcher;
+use crate::wire_mock::matcher::{SimulatedRequest, StubEvaluation};
+use async_trait::async_trait;
+use std::error::Error;
+use std::time::Duration;
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+/// How many recent journal entries are searched for the request just sent.
+const JOURNAL_LOOKBACK: usize = 20;
+
+/// A test request gives up after this long, so a hanging stub shows up as an
+/// error.
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Reading the journal after a test request gives up after this long.
+const JOURNAL_TIMEOUT: Duration = Duration::from_secs(3);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TesterField {
+    Method,
+    Path,
+    Headers,
+    Body,
+}
+
+/// What the journal says about the request that was sent.
+#[derive(Clone, Debug)]
+pub enum MatchOutcome {
+    Matched(String),
+    NearMisses(Vec<NearMiss>),
+    NotInJournal,
+}
+
+pub struct TesterModel {
+    pub selected_server_url: Option<String>,
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub method: String,
+    pub path: String,
+    pub headers: String,
+    pub body: String,
+    pub focused_field: TesterField,
+    pub response: Option<TestResponse>,
+    pub outcome: Option<MatchOutcome>,
+    /// Stubs loaded on the stub screen, used for offline simulation.
+    pub stubs: Vec<StubMapping>,
+    pub simulation: Option<Vec<StubEvaluation>>,
+    pub scroll_offset: usize,
+    pub status_message: Option<String>,
+    /// Whether a test request is on its way, so another waits for it.
+    sending: bool,
+}
+
+#[async_trait]
+impl ModelTrait<TesterMsg, TesterCommand> for TesterModel {
+    async fn apply_event(&mut self, event: TesterMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            TesterMsg::FocusNextField => {
+                self.focused_field = match self.focused_field {
+                    TesterField::Method => TesterField::Path,
+                    TesterField::Path => TesterField::Headers,
+                    TesterField::Headers => TesterField::Body,
+                    TesterField::Body => TesterField::Method,
+                };
+                Ok(())
+            }
+            TesterMsg::InputChar(c) => {
+                // Method and path are single line; Enter sends from there
+                if c == '\n'
+                    && matches!(self.focused_field, TesterField::Method | TesterField::Path)
+                {
+                    self.command_sender
+                        .send(Command::Tester(TesterCommand::SendRequest))?;
+                } else {
+                    self.focused_input().push(c);
+                }
+                Ok(())
+            }
+            TesterMsg::InputBackspace => {
+                self.focused_input().pop();
+                Ok(())
+            }
+            TesterMsg::ScrollResponseUp => {
+                self.scroll_offset = self.scroll_offset.saturating_sub(1);
+                Ok(())
+            }
+            TesterMsg::ScrollResponseDown => {
+                self.scroll_offset += 1;
+                Ok(())
+            }
+            TesterMsg::SendRequested => {
+                self.command_sender
+                    .send(Command::Tester(TesterCommand::SendRequest))?;
+                Ok(())
+            }
+            TesterMsg::Sent(server_url, result) => {
+                self.sending = false;
+                if self.selected_server_url.as_ref() != Some(&server_url) {
+                    return Ok(());
+                }
+                match result {
+                    Ok((status, response, outcome)) => {
+                        self.status_message = Some(status);
+                        self.response = Some(response);
+                        self.outcome = outcome;
+                    }
+                    Err(err) => self.status_message = Some(err),
+                }
+                Ok(())
+            }
+            TesterMsg::SimulateRequested => {
+                if let Err(err) = self.simulate() {
+                    self.status_message = Some(err.to_string());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: TesterCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            TesterCommand::SendRequest => self.send_request(),
+        };
+        if let Err(err) = result {
+            self.status_message = Some(err.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl TesterModel {
+    pub fn new(event_sender: Sender<Message>, command_sender: Sender<Command>) -> Self {
+        Self {
+            selected_server_url: None,
+            event_sender,
+            command_sender,
+            method: "GET".to_string(),
+            path: "/".to_string(),
+            headers: String::new(),
+            body: String::new(),
+            focused_field: TesterField::Path,
+            response: None,
+            outcome: None,
+            stubs: vec![],
+            simulation: None,
+            scroll_offset: 0,
+            status_message: None,
+            sending: false,
+        }
+    }
+
+    fn focused_input(&mut self) -> &mut String {
+        match self.focused_field {
+            TesterField::Method => &mut self.method,
+            TesterField::Path => &mut self.path,
+            TesterField::Headers => &mut self.headers,
+            TesterField::Body => &mut self.body,
+        }
+    }
+
+    /// Parses the headers input, one `Name: value` pair per line.
+    fn parse_headers(&self) -> Result<Vec<(String, String)>, TesterError> {
+        self.headers
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                line.split_once(':')
+                    .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+                    .ok_or_else(|| TesterError::InvalidHeader(line.to_string()))
+            })
+            .collect()
+    }
+
+    /// Validates the form and normalises method and path.
+    fn request(&self) -> Result<SimulatedRequest, TesterError> {
+        let method = self.method.trim().to_uppercase();
+        if method.is_empty() {
+            return Err(TesterError::MissingMethod);
+        }
+        let url = if self.path.starts_with('/') {
+            self.path.clone()
+        } else {
+            format!("/{}", self.path)
+        };
+        if url.starts_with("/__admin") {
+            return Err(TesterError::AdminPath);
+        }
+        Ok(SimulatedRequest {
+            method,
+            url,
+            headers: self.parse_headers()?,
+            body: self.body.clone(),
+        })
+    }
+
+    /// Matches the request against the loaded stubs without sending it.
+    fn simulate(&mut self) -> Result<(), TesterError> {
+        let request = self.request()?;
+        let simulation = matcher::simulate(&self.stubs, &request);
+        let winner = matcher::winner(&simulation);
+        let undecided = simulation[..winner.unwrap_or(simulation.len())]
+            .iter()
+            .filter(|e| e.is_undecided())
+            .count();
+        self.status_message = Some(match (winner, undecided) {
+            (Some(winner), 0) => format!(
+                "Simulated {} {}: stub {} would serve it",
+                request.method, request.url, self.stubs[simulation[winner].index].id
+            ),
+            (Some(winner), undecided) => format!(
+                "Simulated {} {}: stub {} would serve it, unless one of {} undecided stubs before it matches",
+                request.method, request.url, self.stubs[simulation[winner].index].id, undecided
+            ),
+            (None, 0) => format!(
+                "Simulated {} {}: no stub would match",
+                request.method, request.url
+            ),
+            (None, undecided) => format!(
+                "Simulated {} {}: no stub matches for sure, {} cannot be decided offline",
+                request.method, request.url, undecided
+            ),
+        });
+        self.response = None;
+        self.outcome = None;
+        self.scroll_offset = 0;
+        self.simulation = Some(simulation);
+        Ok(())
+    }
+
+    /// Sends the request on a blocking thread, so a slow stub never stalls
+    /// the UI, and looks it up in the journal once it is answered.
+    fn send_request(&mut self) -> Result<(), Box<dyn Error>> {
+        let server_url = self
+            .selected_server_url
+            .clone()
+            .ok_or(TesterError::NoServerSelected)?;
+        if self.sending {
+            return Ok(());
+        }
+        let SimulatedRequest {
+            method,
+            url: path,
+            headers,
+            body,
+        } = self.request()?;
+
+        self.response = None;
+        self.outcome = None;
+        self.simulation = None;
+        self.scroll_offset = 0;
+        self.sending = true;
+        self.status_message = Some(format!("Sending {} {}…", method, path));
+        let sender = self.event_sender.clone();
+        tokio::task::spawn_blocking(move || {
+            let result = wire_mock::client::send_request(
+                &server_url,
+                &method,
+                &path,
+                &headers,
+                &body,
+                SEND_TIMEOUT,
+            )
+            .map(|response| {
+                let status = format!(
+                    "{} {} → {} in {} ms",
+                    method,
+                    path,
+                    response.status,
+                    response.elapsed.as_millis()
+                );
+                // The response stands on its own when the journal cannot be read
+                let outcome = Self::find_outcome(&server_url, &method, &path).ok();
+                (status, response, outcome)
+            })
+            .map_err(|err| format!("{} {}: {}", method, path, err));
+            let _ = sender.send(Message::Tester(TesterMsg::Sent(server_url, result)));
+        });
+        Ok(())
+    }
+
+    /// Looks the request up in the journal (newest first) to tell which stub
+    /// served it, or which stubs came closest when none did.
+    fn find_outcome(
+        server_url: &str,
+        method: &str,
+        path: &str,
+    ) -> Result<MatchOutcome, Box<dyn Error>> {
+        let requests =
+            wire_mock::client::get_recent_requests(server_url, JOURNAL_LOOKBACK, JOURNAL_TIMEOUT)?
+                .requests;
+        let Some(event) = requests
+            .iter()
+            .find(|e| e.request.method == method && e.request.url == path)
+        else {
+            return Ok(MatchOutcome::NotInJournal);
+        };
+        match event.matched_stub_id() {
+            Some(id) => Ok(MatchOutcome::Matched(id.to_string())),
+            None => Ok(MatchOutcome::NearMisses(
+                wire_mock::client::find_near_misses(server_url, &event.request)?,
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TesterCommand {
+    SendRequest,
+}
+
+#[derive(Clone, Debug)]
+pub enum TesterMsg {
+    FocusNextField,
+    InputChar(char),
+    InputBackspace,
+    ScrollResponseUp,
+    ScrollResponseDown,
+    SendRequested,
+    /// A test request answered: its status line, the response and what the
+    /// journal says about it.
+    Sent(
+        String,
+        Result<(String, TestResponse, Option<MatchOutcome>), String>,
+    ),
+    SimulateRequested,
+}
+
+#[derive(Error, Debug)]
+pub enum TesterError {
+    #[error("No server selected")]
+    NoServerSelected,
+    #[error("Method must not be empty")]
+    MissingMethod,
+    #[error("Admin endpoints cannot be tested here")]
+    AdminPath,
+    #[error("Invalid header line (expected `Name: value`): {0}")]
+    InvalidHeader(String),
+}
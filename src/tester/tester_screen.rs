@@ -0,0 +1,338 @@
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::tester::model::{MatchOutcome, TesterField, TesterModel, TesterMsg};
+use crate::ui;
+use crate::ui::theme::Theme;
+use crate::wire_mock::matcher;
+use async_trait::async_trait;
+use crossterm::event::{Event, KeyCode};
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::prelude::{Line, Modifier, Style, Text};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct TesterScreen {
+    sender: Sender<Message>,
+}
+
+impl TesterScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        TesterScreen { sender }
+    }
+
+    fn render_input(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        model: &TesterModel,
+        theme: &Theme,
+        field: TesterField,
+        title: &str,
+    ) {
+        let value = match field {
+            TesterField::Method => &model.method,
+            TesterField::Path => &model.path,
+            TesterField::Headers => &model.headers,
+            TesterField::Body => &model.body,
+        };
+        let (text, style) = if model.focused_field == field {
+            (format!("{}█", value), theme.highlight())
+        } else {
+            (value.clone(), Style::default())
+        };
+        let input = Paragraph::new(text).wrap(Wrap { trim: false }).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(style)
+                .title(title.to_string()),
+        );
+        frame.render_widget(input, area);
+    }
+
+    fn get_response_text(&self, model: &TesterModel) -> String {
+        let Some(response) = &model.response else {
+            return "No request sent yet".to_string();
+        };
+        let mut lines = vec![format!(
+            "HTTP {} ({} ms)",
+            response.status,
+            response.elapsed.as_millis()
+        )];
+        lines.extend(
+            response
+                .headers
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value)),
+        );
+        lines.push(String::new());
+        lines.push(response.body.clone());
+        lines.join("\n")
+    }
+
+    fn render_result(&self, frame: &mut Frame, area: Rect, model: &TesterModel, theme: &Theme) {
+        let (outcome, outcome_style) = self.get_outcome_text(model, theme);
+        let outcome_height = outcome.lines().count() as u16 + 2;
+        let result_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Min(0), Constraint::Length(outcome_height)])
+            .split(area);
+        let response = Paragraph::new(self.get_response_text(model))
+            .block(Block::default().borders(Borders::ALL).title("Response"))
+            .wrap(Wrap { trim: false })
+            .scroll((model.scroll_offset as u16, 0));
+        frame.render_widget(response, result_layout[0]);
+        let outcome = Paragraph::new(outcome)
+            .style(outcome_style)
+            .block(Block::default().borders(Borders::ALL).title("Match"));
+        frame.render_widget(outcome, result_layout[1]);
+    }
+
+    fn get_simulation_text(&self, model: &TesterModel, theme: &Theme) -> Text<'static> {
+        let Some(simulation) = &model.simulation else {
+            return Text::default();
+        };
+        if simulation.is_empty() {
+            return Text::from("No stubs loaded");
+        }
+        let winner = matcher::winner(simulation);
+        let mut undecided_above = false;
+        let mut lines = vec![];
+        for (position, evaluation) in simulation.iter().enumerate() {
+            let stub = &model.stubs[evaluation.index];
+            let summary = format!(
+                "{} {} [priority {}] {}",
+                stub.request.method,
+                stub.request.display_url().unwrap_or("(any url)"),
+                stub.priority.unwrap_or(matcher::DEFAULT_PRIORITY),
+                stub.id
+            );
+            if Some(position) == winner {
+                let note = if undecided_above {
+                    "would serve unless an undecided stub above matches"
+                } else {
+                    "would serve"
+                };
+                lines.push(
+                    Line::from(format!("▶ ✔ {}  ({})", summary, note))
+                        .style(theme.success().add_modifier(Modifier::BOLD)),
+                );
+            } else if evaluation.is_undecided() {
+                undecided_above = true;
+                lines.push(
+                    Line::from(format!("  ? {}  (undecided)", summary)).style(theme.warning()),
+                );
+                lines.extend(
+                    evaluation
+                        .unknowns
+                        .iter()
+                        .map(|reason| Line::from(format!("      {}", reason))),
+                );
+            } else if evaluation.is_match() {
+                lines.push(
+                    Line::from(format!("  ✔ {}  (shadowed)", summary)).style(theme.highlight()),
+                );
+            } else {
+                lines.push(Line::from(format!("  ✘ {}", summary)).style(theme.error()));
+                lines.extend(
+                    evaluation
+                        .mismatches
+                        .iter()
+                        .map(|reason| Line::from(format!("      {}", reason))),
+                );
+            }
+        }
+        Text::from(lines)
+    }
+
+    fn get_outcome_text(&self, model: &TesterModel, theme: &Theme) -> (String, Style) {
+        match &model.outcome {
+            None => (String::new(), Style::default()),
+            Some(MatchOutcome::Matched(id)) => (format!("Matched stub {}", id), theme.success()),
+            Some(MatchOutcome::NotInJournal) => (
+                "Request not found in the journal".to_string(),
+                theme.muted(),
+            ),
+            Some(MatchOutcome::NearMisses(near_misses)) if near_misses.is_empty() => (
+                "No stub matched and there are no near misses".to_string(),
+                theme.error(),
+            ),
+            Some(MatchOutcome::NearMisses(near_misses)) => {
+                let mut lines = vec!["No stub matched. Near misses:".to_string()];
+                lines.extend(
+                    near_misses
+                        .iter()
+                        .map(|near_miss| match &near_miss.stub_mapping {
+                            Some(stub) => format!(
+                                "{:.3}  {} {} {}",
+                                near_miss.match_result.distance,
+                                stub.request.method,
+                                stub.request.display_url().unwrap_or("(no url)"),
+                                stub.id
+                            ),
+                            None => {
+                                format!("{:.3}  (unknown stub)", near_miss.match_result.distance)
+                            }
+                        }),
+                );
+                (lines.join("\n"), theme.error())
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for TesterScreen {
+    fn scope(&self) -> Scope {
+        Scope::Tester
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.tester_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph("Wire Mock - Request Tester", &app.theme);
+        frame.render_widget(title, main_layout[0]);
+
+        // Method and path
+        let request_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Length(12), Constraint::Min(0)])
+            .split(main_layout[1]);
+        self.render_input(
+            frame,
+            request_layout[0],
+            model,
+            &app.theme,
+            TesterField::Method,
+            "Method",
+        );
+        self.render_input(
+            frame,
+            request_layout[1],
+            model,
+            &app.theme,
+            TesterField::Path,
+            "Path",
+        );
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(main_layout[2]);
+
+        // Headers and body (left side)
+        let input_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(content_layout[0]);
+        self.render_input(
+            frame,
+            input_layout[0],
+            model,
+            &app.theme,
+            TesterField::Headers,
+            "Headers (Name: value)",
+        );
+        self.render_input(
+            frame,
+            input_layout[1],
+            model,
+            &app.theme,
+            TesterField::Body,
+            "Body",
+        );
+
+        // Offline simulation, or response and match (right side)
+        if model.simulation.is_some() {
+            let simulation = Paragraph::new(self.get_simulation_text(model, &app.theme))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("Simulated match order"),
+                )
+                .wrap(Wrap { trim: false })
+                .scroll((model.scroll_offset as u16, 0));
+            frame.render_widget(simulation, content_layout[1]);
+        } else {
+            self.render_result(frame, content_layout[1], model, &app.theme);
+        }
+        // Status
+        let status = Paragraph::new(model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[3]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Tester, &[]);
+        ui::widgets::render_commands(frame, main_layout[4], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::Send => {
+                self.sender
+                    .send(Message::Tester(TesterMsg::SendRequested))?;
+                Ok(())
+            }
+            Action::Simulate => {
+                self.sender
+                    .send(Message::Tester(TesterMsg::SimulateRequested))?;
+                Ok(())
+            }
+            Action::NextField => {
+                self.sender
+                    .send(Message::Tester(TesterMsg::FocusNextField))?;
+                Ok(())
+            }
+            Action::ScrollUp => {
+                self.sender
+                    .send(Message::Tester(TesterMsg::ScrollResponseUp))?;
+                Ok(())
+            }
+            Action::ScrollDown => {
+                self.sender
+                    .send(Message::Tester(TesterMsg::ScrollResponseDown))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn handle_key_event(&self, event: &Event) -> Result<(), Box<dyn std::error::Error>> {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(c) => {
+                    self.sender.send(Message::Tester(TesterMsg::InputChar(c)))?;
+                    Ok(())
+                }
+                KeyCode::Enter => {
+                    self.sender
+                        .send(Message::Tester(TesterMsg::InputChar('\n')))?;
+                    Ok(())
+                }
+                KeyCode::Backspace => {
+                    self.sender
+                        .send(Message::Tester(TesterMsg::InputBackspace))?;
+                    Ok(())
+                }
+                _ => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use crate::model::{Command, Message, ModelTrait};
+use crate::server::model::ReadOnlyServers;
+use crate::stub::model::StubMsg;
+use crate::wire_mock;
+use crate::wire_mock::client::StubMapping;
+use async_trait::async_trait;
+use std::error::Error;
+use thiserror::Error;
+use tokio::sync::broadcast::Sender;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransferMode {
+    Copy,
+    Move,
+}
+
+#[derive(Clone, Debug)]
+pub struct TransferResult {
+    pub stub: String,
+    pub outcome: Result<String, String>,
+}
+
+pub struct TransferModel {
+    pub event_sender: Sender<Message>,
+    pub command_sender: Sender<Command>,
+    pub source_server_url: Option<String>,
+    pub server_list: Vec<String>,
+    pub read_only_servers: ReadOnlyServers,
+    pub target_server_index: Option<usize>,
+    pub stubs: Vec<StubMapping>,
+    pub mode: TransferMode,
+    pub keep_ids: bool,
+    pub results: Vec<TransferResult>,
+    pub status_message: Option<String>,
+}
+
+#[async_trait]
+impl ModelTrait<TransferMsg, TransferCommand> for TransferModel {
+    async fn apply_event(&mut self, event: TransferMsg) -> Result<(), Box<dyn Error>> {
+        match event {
+            TransferMsg::SelectNextTarget => {
+                if let Some(index) = self.target_server_index {
+                    self.target_server_index =
+                        Some((index + 1).min(self.server_list.len().saturating_sub(1)));
+                }
+                Ok(())
+            }
+            TransferMsg::SelectPreviousTarget => {
+                if let Some(index) = self.target_server_index {
+                    self.target_server_index = Some(index.saturating_sub(1));
+                }
+                Ok(())
+            }
+            TransferMsg::ToggleKeepIds => {
+                self.keep_ids = !self.keep_ids;
+                Ok(())
+            }
+            TransferMsg::TransferRequested => {
+                self.command_sender
+                    .send(Command::Transfer(TransferCommand::TransferStubs))?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn handle_command(&mut self, command: TransferCommand) -> Result<(), Box<dyn Error>> {
+        let result = match command {
+            TransferCommand::TransferStubs => self.transfer_stubs(),
+        };
+        if let Err(err) = result {
+            self.status_message = Some(err.to_string());
+        }
+        Ok(())
+    }
+}
+
+impl TransferModel {
+    pub fn new(
+        event_sender: Sender<Message>,
+        command_sender: Sender<Command>,
+        read_only_servers: ReadOnlyServers,
+    ) -> Self {
+        Self {
+            event_sender,
+            command_sender,
+            source_server_url: None,
+            server_list: vec![],
+            read_only_servers,
+            target_server_index: None,
+            stubs: vec![],
+            mode: TransferMode::Copy,
+            keep_ids: true,
+            results: vec![],
+            status_message: None,
+        }
+    }
+
+    /// Prepares a new transfer, preselecting the first server that is not the source.
+    pub fn reset(
+        &mut self,
+        source_server_url: Option<String>,
+        server_list: Vec<String>,
+        stubs: Vec<StubMapping>,
+        mode: TransferMode,
+    ) {
+        self.target_server_index = server_list
+            .iter()
+            .position(|s| Some(s) != source_server_url.as_ref())
+            .or(if server_list.is_empty() {
+                None
+            } else {
+                Some(0)
+            });
+        self.source_server_url = source_server_url;
+        self.server_list = server_list;
+        self.stubs = stubs;
+        self.mode = mode;
+        self.results.clear();
+        self.status_message = None;
+    }
+
+    pub fn target_server(&self) -> Option<&String> {
+        self.target_server_index
+            .and_then(|i| self.server_list.get(i))
+    }
+
+    fn transfer_stubs(&mut self) -> Result<(), Box<dyn Error>> {
+        let source_url = self
+            .source_server_url
+            .clone()
+            .ok_or(TransferError::NoSourceServer)?;
+        let target_url = self
+            .target_server()
+            .cloned()
+            .ok_or(TransferError::NoTargetServer)?;
+        if source_url == target_url {
+            return Err(Box::new(TransferError::SameServer));
+        }
+        if self.stubs.is_empty() {
+            return Err(Box::new(TransferError::NothingToTransfer));
+        }
+        self.read_only_servers.check(
+            &target_url,
+            &format!("transferring stubs to {}", target_url),
+        )?;
+        if self.mode == TransferMode::Move {
+            self.read_only_servers
+                .check(&source_url, &format!("moving stubs off {}", source_url))?;
+        }
+
+        let existing_ids: Vec<String> = if self.keep_ids {
+            wire_mock::client::get_all_stubs(&target_url)?
+                .mappings
+                .into_iter()
+                .map(|s| s.id)
+                .collect()
+        } else {
+            vec![]
+        };
+
+        let mut transferred = vec![];
+        self.results = self
+            .stubs
+            .iter()
+            .map(|stub| {
+                let outcome = self
+                    .transfer_stub(stub, &source_url, &target_url, &existing_ids)
+                    .map_err(|e| e.to_string());
+                if outcome.is_ok() {
+                    transferred.push(stub.id.clone());
+                }
+                TransferResult {
+                    stub: format!(
+                        "{} {}",
+                        stub.request.method,
+                        stub.request.display_url().unwrap_or("(no url)")
+                    ),
+                    outcome,
+                }
+            })
+            .collect();
+        self.stubs.retain(|s| !transferred.contains(&s.id));
+
+        self.event_sender
+            .send(Message::Stub(StubMsg::ReadAllStubsRequested))?;
+        let failed = self.results.iter().filter(|r| r.outcome.is_err()).count();
+        self.status_message = Some(format!(
+            "{} of {} stubs {} to {}",
+            self.results.len() - failed,
+            self.results.len(),
+            match self.mode {
+                TransferMode::Copy => "copied",
+                TransferMode::Move => "moved",
+            },
+            target_url
+        ));
+        Ok(())
+    }
+
+    fn transfer_stub(
+        &self,
+        stub: &StubMapping,
+        source_url: &str,
+        target_url: &str,
+        existing_ids: &[String],
+    ) -> Result<String, Box<dyn Error>> {
+        let mut mapping = stub.clone();
+        mapping.extra.remove("uuid");
+        let summary = if self.keep_ids && existing_ids.contains(&mapping.id) {
+            wire_mock::client::update_stub(target_url, &mapping.id, &mapping)?;
+            format!("updated {}", mapping.id)
+        } else {
+            if !self.keep_ids {
+                mapping.id.clear();
+            }
+            let created = wire_mock::client::create_stub(target_url, &mapping)?;
+            format!("created {}", created.id)
+        };
+        if self.mode == TransferMode::Move {
+            wire_mock::client::delete_stub(source_url, &stub.id)?;
+        }
+        Ok(summary)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TransferCommand {
+    TransferStubs,
+}
+
+#[derive(Clone, Debug)]
+pub enum TransferMsg {
+    SelectNextTarget,
+    SelectPreviousTarget,
+    ToggleKeepIds,
+    TransferRequested,
+}
+
+#[derive(Error, Debug)]
+pub enum TransferError {
+    #[error("No source server selected")]
+    NoSourceServer,
+    #[error("No target server selected")]
+    NoTargetServer,
+    #[error("Source and target server are the same")]
+    SameServer,
+    #[error("No stubs left to transfer")]
+    NothingToTransfer,
+}
@@ -0,0 +1,140 @@
+use crate::keymap::action::{Action, Scope};
+use crate::model::ScreenTrait;
+use crate::model::{ApplicationModel, GlobalMsg, Message};
+use crate::transfer::model::{TransferMode, TransferMsg};
+use crate::ui;
+use async_trait::async_trait;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::Frame;
+use tokio::sync::broadcast::Sender;
+
+pub struct TransferScreen {
+    sender: Sender<Message>,
+}
+
+impl TransferScreen {
+    pub fn new(sender: Sender<Message>) -> Self {
+        TransferScreen { sender }
+    }
+}
+
+#[async_trait]
+impl ScreenTrait for TransferScreen {
+    fn scope(&self) -> Scope {
+        Scope::Transfer
+    }
+
+    fn draw(&self, app: &ApplicationModel, frame: &mut Frame) {
+        let model = &app.transfer_model;
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Min(0),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ])
+            .split(frame.area());
+
+        // Title
+        let title = ui::widgets::title_paragraph(
+            match model.mode {
+                TransferMode::Copy => "Wire Mock - Copy Stubs To Server",
+                TransferMode::Move => "Wire Mock - Move Stubs To Server",
+            },
+            &app.theme,
+        );
+        frame.render_widget(title, main_layout[0]);
+
+        let content_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(main_layout[1]);
+
+        // Target server picker (left side)
+        let server_list = ui::widgets::server_list(
+            &model.server_list,
+            model.target_server_index,
+            "Target server",
+            Some(&app.server_model.health),
+            &app.theme,
+        );
+        frame.render_widget(server_list, content_layout[0]);
+
+        // Pending stubs, or the report of the last transfer (right side)
+        let (items, list_title): (Vec<ListItem>, String) = if model.results.is_empty() {
+            let items = model
+                .stubs
+                .iter()
+                .map(|stub| {
+                    ListItem::new(format!(
+                        "  {} {}",
+                        stub.request.method,
+                        stub.request.display_url().unwrap_or("(no url)")
+                    ))
+                })
+                .collect();
+            let ids = if model.keep_ids { "keep" } else { "regenerate" };
+            (items, format!("Stubs to transfer - ids: {}", ids))
+        } else {
+            let items = model
+                .results
+                .iter()
+                .map(|result| match &result.outcome {
+                    Ok(summary) => ListItem::new(format!("✓ {} ({})", result.stub, summary))
+                        .style(app.theme.success()),
+                    Err(error) => ListItem::new(format!("✗ {} ({})", result.stub, error))
+                        .style(app.theme.error()),
+                })
+                .collect();
+            (items, "Result".to_string())
+        };
+        let stub_list =
+            List::new(items).block(Block::default().borders(Borders::ALL).title(list_title));
+        frame.render_widget(stub_list, content_layout[1]);
+
+        // Status
+        let status = Paragraph::new(model.status_message.clone().unwrap_or_default());
+        frame.render_widget(status, main_layout[2]);
+
+        // Commands
+        let commands = app.keymap.commands(Scope::Transfer, &[]);
+        ui::widgets::render_commands(frame, main_layout[3], &commands);
+    }
+
+    async fn handle_action(&self, action: Action) -> Result<(), Box<dyn std::error::Error>> {
+        match action {
+            Action::Quit => {
+                self.sender.send(Message::QuitRequested)?;
+                Ok(())
+            }
+            Action::Back => {
+                self.sender
+                    .send(Message::Global(GlobalMsg::SwitchToStubScreen))?;
+                Ok(())
+            }
+            Action::Up => {
+                self.sender
+                    .send(Message::Transfer(TransferMsg::SelectPreviousTarget))?;
+                Ok(())
+            }
+            Action::Down => {
+                self.sender
+                    .send(Message::Transfer(TransferMsg::SelectNextTarget))?;
+                Ok(())
+            }
+            Action::KeepIds => {
+                self.sender
+                    .send(Message::Transfer(TransferMsg::ToggleKeepIds))?;
+                Ok(())
+            }
+            Action::Transfer => {
+                self.sender
+                    .send(Message::Transfer(TransferMsg::TransferRequested))?;
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
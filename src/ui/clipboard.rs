@@ -0,0 +1,10 @@
+use crate::ui::terminal;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::io;
+
+/// Copies text to the system clipboard with an OSC 52 escape sequence, which
+/// the terminal emulator handles, so it also works over SSH.
+pub fn copy_to_clipboard(text: &str) -> io::Result<()> {
+    terminal::write_escape_sequence(&format!("\x1b]52;c;{}\x07", STANDARD.encode(text)))
+}
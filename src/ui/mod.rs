@@ -1 +1,6 @@
-pub mod widgets;
\ No newline at end of file
+pub mod clipboard;
+pub mod mouse;
+pub mod notification;
+pub mod terminal;
+pub mod theme;
+pub mod widgets;
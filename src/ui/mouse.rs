@@ -0,0 +1,68 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::{Position, Rect};
+use std::time::{Duration, Instant};
+
+/// Longest pause between the clicks of a double-click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// What the user did with the mouse, at which cell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MouseGesture {
+    Click(Position),
+    DoubleClick(Position),
+    /// The left button moved while held down.
+    Drag(Position),
+    Release(Position),
+    ScrollUp(Position),
+    ScrollDown(Position),
+}
+
+/// Turns raw mouse events into gestures, remembering the last click to spot
+/// double-clicks.
+#[derive(Default)]
+pub struct MouseTracker {
+    last_click: Option<(Instant, Position)>,
+}
+
+impl MouseTracker {
+    pub fn gesture(&mut self, event: &MouseEvent) -> Option<MouseGesture> {
+        let position = Position::new(event.column, event.row);
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let now = Instant::now();
+                let double = self.last_click.is_some_and(|(at, last)| {
+                    last == position && now.duration_since(at) <= DOUBLE_CLICK_INTERVAL
+                });
+                if double {
+                    // A third click starts over rather than double-clicking again
+                    self.last_click = None;
+                    Some(MouseGesture::DoubleClick(position))
+                } else {
+                    self.last_click = Some((now, position));
+                    Some(MouseGesture::Click(position))
+                }
+            }
+            MouseEventKind::Drag(MouseButton::Left) => Some(MouseGesture::Drag(position)),
+            MouseEventKind::Up(MouseButton::Left) => Some(MouseGesture::Release(position)),
+            MouseEventKind::ScrollUp => Some(MouseGesture::ScrollUp(position)),
+            MouseEventKind::ScrollDown => Some(MouseGesture::ScrollDown(position)),
+            _ => None,
+        }
+    }
+}
+
+/// Index of the entry under `position` in a bordered list drawn in `area`,
+/// one entry per row.
+pub fn list_row(area: Rect, position: Position, len: usize) -> Option<usize> {
+    let inner = Rect::new(
+        area.x + 1,
+        area.y + 1,
+        area.width.saturating_sub(2),
+        area.height.saturating_sub(2),
+    );
+    if !inner.contains(position) {
+        return None;
+    }
+    let index = (position.y - inner.y) as usize;
+    (index < len).then_some(index)
+}
@@ -0,0 +1,57 @@
+use crate::ui::terminal;
+use std::io;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// How to raise a desktop notification, on top of the terminal bell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DesktopNotification {
+    Off,
+    /// OSC 9, shown by terminals such as iTerm2, kitty and Windows Terminal.
+    Osc9,
+    NotifySend,
+}
+
+impl DesktopNotification {
+    pub fn next(self) -> Self {
+        match self {
+            DesktopNotification::Off => DesktopNotification::Osc9,
+            DesktopNotification::Osc9 => DesktopNotification::NotifySend,
+            DesktopNotification::NotifySend => DesktopNotification::Off,
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            DesktopNotification::Off => "bell only",
+            DesktopNotification::Osc9 => "bell and OSC 9",
+            DesktopNotification::NotifySend => "bell and notify-send",
+        }
+    }
+}
+
+pub fn ring_bell() -> io::Result<()> {
+    let mut stdout = io::stdout();
+    write!(stdout, "\x07")?;
+    stdout.flush()
+}
+
+pub fn notify(kind: DesktopNotification, title: &str, message: &str) -> io::Result<()> {
+    ring_bell()?;
+    match kind {
+        DesktopNotification::Off => Ok(()),
+        DesktopNotification::Osc9 => {
+            terminal::write_escape_sequence(&format!("\x1b]9;{}: {}\x07", title, message))
+        }
+        DesktopNotification::NotifySend => {
+            // Output would corrupt the screen
+            let mut child = Command::new("notify-send")
+                .args([title, message])
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()?;
+            std::thread::spawn(move || child.wait());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use std::io;
+use std::io::Write;
+
+/// Writes an escape sequence for the terminal emulator itself, wrapping it so
+/// that tmux forwards it instead of swallowing it.
+pub fn write_escape_sequence(sequence: &str) -> io::Result<()> {
+    let mut stdout = io::stdout();
+    if std::env::var_os("TMUX").is_some() {
+        // tmux only forwards escape sequences wrapped in a passthrough
+        write!(
+            stdout,
+            "\x1bPtmux;{}\x1b\\",
+            sequence.replace('\x1b', "\x1b\x1b")
+        )?;
+    } else {
+        write!(stdout, "{}", sequence)?;
+    }
+    stdout.flush()
+}
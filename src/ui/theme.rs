@@ -0,0 +1,250 @@
+use ratatui::prelude::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// User-defined themes from the configuration file, by name.
+pub type ThemeDefinitions = HashMap<String, ThemeDefinition>;
+
+/// The themes that ship with the application.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BuiltinTheme {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl BuiltinTheme {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(BuiltinTheme::Dark),
+            "light" => Some(BuiltinTheme::Light),
+            "high-contrast" => Some(BuiltinTheme::HighContrast),
+            _ => None,
+        }
+    }
+}
+
+/// A theme in the configuration file: a built-in theme with some of its
+/// colors replaced. Colors are names like `light-blue`, indices like `208`
+/// or hex values like `#268bd2`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ThemeDefinition {
+    pub base: BuiltinTheme,
+    pub text: Option<String>,
+    pub title: Option<String>,
+    pub accent: Option<String>,
+    pub selected: Option<String>,
+    pub highlight: Option<String>,
+    pub muted: Option<String>,
+    pub success: Option<String>,
+    pub warning: Option<String>,
+    pub error: Option<String>,
+    pub badge_text: Option<String>,
+    pub badge_background: Option<String>,
+}
+
+/// The colors every screen draws with.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    /// Regular text, such as stub details.
+    pub text: Color,
+    /// Screen titles.
+    pub title: Color,
+    /// Headings and prompts.
+    pub accent: Color,
+    /// The selected entry of a list.
+    pub selected: Color,
+    /// Values being edited and entries that need a look.
+    pub highlight: Color,
+    /// Hints and entries that need no attention.
+    pub muted: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub error: Color,
+    pub badge_text: Color,
+    pub badge_background: Color,
+    /// Shows the selection in reverse video, so it stands out without
+    /// relying on color.
+    pub reverse_selection: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::builtin(BuiltinTheme::Dark)
+    }
+}
+
+impl Theme {
+    pub fn builtin(theme: BuiltinTheme) -> Self {
+        match theme {
+            BuiltinTheme::Dark => Theme {
+                text: Color::White,
+                title: Color::Cyan,
+                accent: Color::Cyan,
+                selected: Color::Yellow,
+                highlight: Color::Yellow,
+                muted: Color::DarkGray,
+                success: Color::Green,
+                warning: Color::LightYellow,
+                error: Color::Red,
+                badge_text: Color::White,
+                badge_background: Color::Red,
+                reverse_selection: false,
+            },
+            BuiltinTheme::Light => Theme {
+                text: Color::Black,
+                title: Color::Blue,
+                accent: Color::Blue,
+                selected: Color::Magenta,
+                highlight: Color::Blue,
+                muted: Color::DarkGray,
+                success: Color::Green,
+                warning: Color::Rgb(175, 95, 0),
+                error: Color::Red,
+                badge_text: Color::White,
+                badge_background: Color::Red,
+                reverse_selection: false,
+            },
+            BuiltinTheme::HighContrast => Theme {
+                text: Color::White,
+                title: Color::LightCyan,
+                accent: Color::LightCyan,
+                selected: Color::LightYellow,
+                highlight: Color::LightYellow,
+                muted: Color::Gray,
+                success: Color::LightGreen,
+                warning: Color::LightYellow,
+                error: Color::LightRed,
+                badge_text: Color::Black,
+                badge_background: Color::LightRed,
+                reverse_selection: true,
+            },
+        }
+    }
+
+    /// The terminal's own colors only, for `NO_COLOR`.
+    pub fn monochrome() -> Self {
+        Theme {
+            text: Color::Reset,
+            title: Color::Reset,
+            accent: Color::Reset,
+            selected: Color::Reset,
+            highlight: Color::Reset,
+            muted: Color::Reset,
+            success: Color::Reset,
+            warning: Color::Reset,
+            error: Color::Reset,
+            badge_text: Color::Reset,
+            badge_background: Color::Reset,
+            reverse_selection: true,
+        }
+    }
+
+    /// Picks the named theme, built-in or from `definitions`, or the dark
+    /// theme without a name. A non-empty `NO_COLOR` variable wins over both.
+    pub fn resolve(name: Option<&str>, definitions: &ThemeDefinitions) -> Result<Self, ThemeError> {
+        if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Ok(Theme::monochrome());
+        }
+        let Some(name) = name else {
+            return Ok(Theme::default());
+        };
+        match definitions.get(name) {
+            Some(definition) => Theme::from_definition(name, definition),
+            None => BuiltinTheme::from_name(name)
+                .map(Theme::builtin)
+                .ok_or_else(|| ThemeError::UnknownTheme(name.to_string())),
+        }
+    }
+
+    fn from_definition(name: &str, definition: &ThemeDefinition) -> Result<Self, ThemeError> {
+        let mut theme = Theme::builtin(definition.base);
+        let colors = [
+            (&mut theme.text, &definition.text, "text"),
+            (&mut theme.title, &definition.title, "title"),
+            (&mut theme.accent, &definition.accent, "accent"),
+            (&mut theme.selected, &definition.selected, "selected"),
+            (&mut theme.highlight, &definition.highlight, "highlight"),
+            (&mut theme.muted, &definition.muted, "muted"),
+            (&mut theme.success, &definition.success, "success"),
+            (&mut theme.warning, &definition.warning, "warning"),
+            (&mut theme.error, &definition.error, "error"),
+            (&mut theme.badge_text, &definition.badge_text, "badge_text"),
+            (
+                &mut theme.badge_background,
+                &definition.badge_background,
+                "badge_background",
+            ),
+        ];
+        for (color, value, role) in colors {
+            if let Some(value) = value {
+                *color = Color::from_str(value)
+                    .map_err(|_| ThemeError::InvalidColor(name.to_string(), role, value.clone()))?;
+            }
+        }
+        Ok(theme)
+    }
+
+    pub fn text(&self) -> Style {
+        Style::default().fg(self.text)
+    }
+
+    pub fn title(&self) -> Style {
+        Style::default().fg(self.title).add_modifier(Modifier::BOLD)
+    }
+
+    pub fn accent(&self) -> Style {
+        Style::default().fg(self.accent)
+    }
+
+    pub fn selected(&self) -> Style {
+        let style = Style::default()
+            .fg(self.selected)
+            .add_modifier(Modifier::BOLD);
+        if self.reverse_selection {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style
+        }
+    }
+
+    pub fn highlight(&self) -> Style {
+        Style::default().fg(self.highlight)
+    }
+
+    pub fn muted(&self) -> Style {
+        Style::default().fg(self.muted)
+    }
+
+    pub fn success(&self) -> Style {
+        Style::default().fg(self.success)
+    }
+
+    pub fn warning(&self) -> Style {
+        Style::default().fg(self.warning)
+    }
+
+    pub fn error(&self) -> Style {
+        Style::default().fg(self.error)
+    }
+
+    pub fn badge(&self) -> Style {
+        Style::default()
+            .fg(self.badge_text)
+            .bg(self.badge_background)
+            .add_modifier(Modifier::BOLD)
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ThemeError {
+    #[error("Unknown theme {0:?}; use dark, light, high-contrast or one from [themes]")]
+    UnknownTheme(String),
+    #[error("Invalid {1} color {2:?} in theme {0:?}")]
+    InvalidColor(String, &'static str, String),
+}
@@ -1,12 +1,127 @@
-use ratatui::prelude::{Color, Modifier, Style};
-use ratatui::widgets::{Block, Borders, Paragraph};
+use crate::server::model::ServerHealth;
+use crate::ui::theme::Theme;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::prelude::{Line, Modifier, Span, Style};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::Frame;
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-pub fn title_paragraph(text: &str) -> Paragraph<'_> {
+pub fn title_paragraph<'a>(text: &'a str, theme: &Theme) -> Paragraph<'a> {
     Paragraph::new(text)
-        .style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )
+        .style(theme.title())
         .block(Block::default().borders(Borders::ALL))
 }
+
+/// Title of a screen working on one server, badged when it is read-only.
+pub fn server_title_paragraph<'a>(text: &'a str, read_only: bool, theme: &Theme) -> Paragraph<'a> {
+    let mut spans = vec![Span::styled(text, theme.title())];
+    if read_only {
+        spans.push(Span::raw("  "));
+        spans.push(Span::styled(" READ-ONLY ", theme.badge()));
+    }
+    Paragraph::new(Line::from(spans)).block(Block::default().borders(Borders::ALL))
+}
+
+/// Footer listing the screen's most important commands. They flow over as
+/// many lines as the area has, and those that do not fit are left out; the
+/// help overlay lists them all.
+pub fn render_commands(frame: &mut Frame, area: Rect, commands: &[(String, &str)]) {
+    const SEPARATOR: &str = "   ";
+    let mut lines = vec![Line::default()];
+    let mut line_width = 0;
+    for (keys, label) in commands {
+        let entry = vec![
+            Span::styled(keys.clone(), Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(format!(": {}", label)),
+        ];
+        let width: usize = entry.iter().map(Span::width).sum();
+        let separator = if line_width == 0 { 0 } else { SEPARATOR.len() };
+        if line_width > 0 && line_width + separator + width > area.width as usize {
+            if lines.len() >= area.height as usize {
+                continue;
+            }
+            lines.push(Line::default());
+            line_width = 0;
+        }
+        let line = lines.last_mut().expect("footer has a line");
+        if line_width > 0 {
+            line.push_span(SEPARATOR);
+            line_width += SEPARATOR.len();
+        }
+        line_width += width;
+        line.spans.extend(entry);
+    }
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// List of server URLs, annotated with their probe results when given.
+pub fn server_list<'a>(
+    servers: &'a [String],
+    selected_index: Option<usize>,
+    title: &'a str,
+    health: Option<&HashMap<String, ServerHealth>>,
+    theme: &Theme,
+) -> List<'a> {
+    let items: Vec<ListItem> = servers
+        .iter()
+        .enumerate()
+        .map(|(i, server)| {
+            let style = if Some(i) == selected_index {
+                theme.selected()
+            } else {
+                Style::default()
+            };
+            let mut spans = vec![Span::styled(format!("▶ {}", server), style)];
+            if let Some(health) = health {
+                spans.push(health_span(health.get(server), theme));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    List::new(items).block(Block::default().borders(Borders::ALL).title(title))
+}
+
+/// Area for a popup of the given width percentage and height, centered in `area`.
+pub fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let [area] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::horizontal([Constraint::Percentage(percent_x)])
+        .flex(Flex::Center)
+        .areas(area);
+    area
+}
+
+/// Frame of a spinner, advancing with the clock so every redraw on an
+/// animation tick moves it along.
+fn spinner() -> char {
+    const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    FRAMES[(millis / 100) as usize % FRAMES.len()]
+}
+
+fn health_span(health: Option<&ServerHealth>, theme: &Theme) -> Span<'static> {
+    match health {
+        None => Span::styled(format!("  {} probing", spinner()), theme.muted()),
+        Some(ServerHealth::Down(_)) => Span::styled("  ✘ unreachable", theme.error()),
+        Some(ServerHealth::Up {
+            latency,
+            version,
+            mapping_count,
+        }) => {
+            let mut text = format!("  ● up {} ms", latency.as_millis());
+            if let Some(version) = version {
+                text.push_str(&format!(" · WireMock {}", version));
+            }
+            if let Some(count) = mapping_count {
+                text.push_str(&format!(" · {} mappings", count));
+            }
+            Span::styled(text, theme.success())
+        }
+    }
+}
@@ -1,14 +1,17 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use ureq;
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct WireMockStub {
     pub request: RequestPattern,
     pub response: ResponseDefinition,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct RequestPattern {
+    #[serde(default = "any_method")]
     pub method: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -18,26 +21,45 @@ pub struct RequestPattern {
     pub url_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_path_pattern: Option<String>,
+    /// Matchers not modelled above (headers, body patterns, ...), kept so a
+    /// mapping survives a round trip through the TUI unchanged.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct ResponseDefinition {
+    #[serde(default = "ok_status")]
     pub status: u16,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub headers: Option<std::collections::HashMap<String, String>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+fn any_method() -> String {
+    "ANY".to_string()
+}
+
+fn ok_status() -> u16 {
+    200
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct StubMappings {
     pub mappings: Vec<StubMapping>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub meta: Option<Meta>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct StubMapping {
+    /// Empty for mappings read from files that leave the id to WireMock.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub id: String,
     pub request: RequestPattern,
     pub response: ResponseDefinition,
@@ -45,14 +67,37 @@ pub struct StubMapping {
     pub priority: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub scenario_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Meta {
     pub total: usize,
 }
 
-pub fn get_all_stubs(base_url: &String) -> Result<StubMappings, Box<dyn std::error::Error>> {
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DuplicatePolicy {
+    Overwrite,
+    Ignore,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ImportOptions {
+    duplicate_policy: DuplicatePolicy,
+    delete_all_not_in_import: bool,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ImportRequest<'a> {
+    mappings: &'a [StubMapping],
+    import_options: ImportOptions,
+}
+
+pub fn get_all_stubs(base_url: &str) -> Result<StubMappings, Box<dyn std::error::Error>> {
     let url = format!("{}/__admin/mappings", base_url);
 
     let mut response = ureq::get(&url)
@@ -68,7 +113,7 @@ pub fn get_all_stubs(base_url: &String) -> Result<StubMappings, Box<dyn std::err
     }
 }
 
-pub fn delete_stub(base_url: &String, id: &str) -> Result<(), Box<dyn std::error::Error>> {
+pub fn delete_stub(base_url: &str, id: &str) -> Result<(), Box<dyn std::error::Error>> {
     let url = format!("{}/__admin/mappings/{}", base_url, id);
     let response = ureq::delete(&url).call()?;
     let code = response.status().as_u16();
@@ -78,3 +123,405 @@ pub fn delete_stub(base_url: &String, id: &str) -> Result<(), Box<dyn std::error
         Err(format!("Failed to delete stub {}: HTTP {}", id, code).into())
     }
 }
+
+pub fn import_stubs(
+    base_url: &str,
+    mappings: &[StubMapping],
+    duplicate_policy: DuplicatePolicy,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/mappings/import", base_url);
+    let request = ImportRequest {
+        mappings,
+        import_options: ImportOptions {
+            duplicate_policy,
+            delete_all_not_in_import: false,
+        },
+    };
+    let response = ureq::post(&url).send_json(&request)?;
+    let code = response.status().as_u16();
+    if code == 200 {
+        Ok(())
+    } else {
+        Err(format!("Failed to import stubs: HTTP {}", code).into())
+    }
+}
+
+impl StubMapping {
+    /// Compares two mappings while ignoring the server assigned identifiers.
+    pub fn same_definition(&self, other: &StubMapping) -> bool {
+        let strip = |mapping: &StubMapping| {
+            let mut mapping = mapping.clone();
+            mapping.id.clear();
+            mapping.extra.remove("uuid");
+            mapping
+        };
+        strip(self) == strip(other)
+    }
+}
+
+impl RequestPattern {
+    /// The first URL matcher that is set, for showing the stub in a list.
+    pub fn display_url(&self) -> Option<&str> {
+        self.url
+            .as_deref()
+            .or(self.url_path.as_deref())
+            .or(self.url_pattern.as_deref())
+            .or(self.url_path_pattern.as_deref())
+    }
+}
+
+/// Registers a stub, either a full `StubMapping` or a bare `WireMockStub`.
+pub fn create_stub<T: Serialize>(
+    base_url: &str,
+    stub: &T,
+) -> Result<StubMapping, Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/mappings", base_url);
+    let mut response = ureq::post(&url).send_json(stub)?;
+    let code = response.status().as_u16();
+    if code == 200 || code == 201 {
+        Ok(response.body_mut().read_json()?)
+    } else {
+        Err(format!("Failed to create stub: HTTP {}", code).into())
+    }
+}
+
+pub fn update_stub(
+    base_url: &str,
+    id: &str,
+    mapping: &StubMapping,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/mappings/{}", base_url, id);
+    let response = ureq::put(&url).send_json(mapping)?;
+    let code = response.status().as_u16();
+    if code == 200 {
+        Ok(())
+    } else {
+        Err(format!("Failed to update stub {}: HTTP {}", id, code).into())
+    }
+}
+
+pub fn get_stub(
+    base_url: &str,
+    id: &str,
+) -> Result<Option<StubMapping>, Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/mappings/{}", base_url, id);
+    let mut response = match ureq::get(&url).header("Accept", "application/json").call() {
+        Ok(response) => response,
+        Err(ureq::Error::StatusCode(404)) => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+    Ok(Some(response.body_mut().read_json()?))
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggedRequest {
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub absolute_url: Option<String>,
+    pub method: String,
+    #[serde(default)]
+    pub headers: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logged_date: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logged_date_string: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggedResponse {
+    pub status: u16,
+    #[serde(default)]
+    pub headers: Map<String, Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ServeEvent {
+    pub id: String,
+    pub request: LoggedRequest,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_definition: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<LoggedResponse>,
+    #[serde(default)]
+    pub was_matched: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stub_mapping: Option<Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ServeEvents {
+    pub requests: Vec<ServeEvent>,
+    /// Size of the whole journal, also when `requests` holds only the newest.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+}
+
+impl ServeEvent {
+    /// Id of the stub that served the request, if one matched.
+    pub fn matched_stub_id(&self) -> Option<&str> {
+        if !self.was_matched {
+            return None;
+        }
+        self.stub_mapping
+            .as_ref()
+            .and_then(|s| s.get("id"))
+            .and_then(Value::as_str)
+    }
+
+    /// Whether the response came from a proxied upstream.
+    pub fn was_proxied(&self) -> bool {
+        self.response_definition
+            .as_ref()
+            .is_some_and(|d| d.get("proxyBaseUrl").is_some())
+    }
+}
+
+/// Header values are logged as a string or, when repeated, as a list.
+pub fn header_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Array(values) => values
+            .iter()
+            .map(header_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+pub fn get_requests(base_url: &str) -> Result<ServeEvents, Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/requests", base_url);
+
+    let mut response = ureq::get(&url)
+        .header("Accept", "application/json")
+        .call()?;
+
+    let status_code = response.status().as_u16();
+    if status_code == 200 {
+        let data: ServeEvents = response.body_mut().read_json()?;
+        Ok(data)
+    } else {
+        Err(format!("Failed to retrieve requests: HTTP {}", status_code).into())
+    }
+}
+
+/// Reads the newest `limit` journal entries, giving up after `timeout`.
+pub fn get_recent_requests(
+    base_url: &str,
+    limit: usize,
+    timeout: std::time::Duration,
+) -> Result<ServeEvents, Box<dyn std::error::Error>> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .build()
+        .into();
+    let mut response = agent
+        .get(format!("{}/__admin/requests?limit={}", base_url, limit))
+        .header("Accept", "application/json")
+        .call()?;
+    Ok(response.body_mut().read_json()?)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LoggedRequests {
+    pub requests: Vec<LoggedRequest>,
+}
+
+/// Lists the requests no stub matched, giving up after `timeout`.
+pub fn get_unmatched_requests(
+    base_url: &str,
+    timeout: std::time::Duration,
+) -> Result<LoggedRequests, Box<dyn std::error::Error>> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .build()
+        .into();
+    let mut response = agent
+        .get(format!("{}/__admin/requests/unmatched", base_url))
+        .header("Accept", "application/json")
+        .call()?;
+    Ok(response.body_mut().read_json()?)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchResult {
+    pub distance: f64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NearMiss {
+    pub stub_mapping: Option<StubMapping>,
+    pub match_result: MatchResult,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+struct NearMisses {
+    near_misses: Vec<NearMiss>,
+}
+
+/// Response of a request sent to the mock itself rather than the admin API.
+#[derive(Debug, Clone)]
+pub struct TestResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+    pub elapsed: std::time::Duration,
+}
+
+pub fn find_near_misses(
+    base_url: &str,
+    request: &LoggedRequest,
+) -> Result<Vec<NearMiss>, Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/near-misses/request", base_url);
+    let mut response = ureq::post(&url).send_json(request)?;
+    let code = response.status().as_u16();
+    if code == 200 {
+        let data: NearMisses = response.body_mut().read_json()?;
+        Ok(data.near_misses)
+    } else {
+        Err(format!("Failed to retrieve near misses: HTTP {}", code).into())
+    }
+}
+
+pub fn send_request(
+    base_url: &str,
+    method: &str,
+    path: &str,
+    headers: &[(String, String)],
+    body: &str,
+    timeout: std::time::Duration,
+) -> Result<TestResponse, Box<dyn std::error::Error>> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .http_status_as_error(false)
+        .timeout_global(Some(timeout))
+        .build()
+        .into();
+    let mut builder = ureq::http::Request::builder()
+        .method(method)
+        .uri(format!("{}{}", base_url, path));
+    for (name, value) in headers {
+        builder = builder.header(name, value);
+    }
+
+    let started = std::time::Instant::now();
+    let mut response = if body.is_empty() {
+        agent.run(builder.body(())?)?
+    } else {
+        agent.run(builder.body(body.to_string())?)?
+    };
+    let elapsed = started.elapsed();
+
+    let headers = response
+        .headers()
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.to_string(),
+                String::from_utf8_lossy(value.as_bytes()).to_string(),
+            )
+        })
+        .collect();
+    Ok(TestResponse {
+        status: response.status().as_u16(),
+        headers,
+        body: response.body_mut().read_to_string()?,
+        elapsed,
+    })
+}
+
+fn post_admin(base_url: &str, path: &str, action: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/{}", base_url, path);
+    let response = ureq::post(&url).send_empty()?;
+    let code = response.status().as_u16();
+    if code == 200 || code == 204 {
+        Ok(())
+    } else {
+        Err(format!("Failed to {}: HTTP {}", action, code).into())
+    }
+}
+
+/// Removes all stubs, journal entries and scenario state.
+pub fn reset(base_url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    post_admin(base_url, "reset", "reset the server")
+}
+
+/// Restores the mappings loaded from the server's mappings directory.
+pub fn reset_mappings(base_url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    post_admin(base_url, "mappings/reset", "reset mappings")
+}
+
+/// Writes all in-memory mappings to the server's mappings directory.
+pub fn save_mappings(base_url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    post_admin(base_url, "mappings/save", "save mappings")
+}
+
+pub fn reset_scenarios(base_url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    post_admin(base_url, "scenarios/reset", "reset scenarios")
+}
+
+pub fn shutdown(base_url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    post_admin(base_url, "shutdown", "shut down the server")
+}
+
+pub fn clear_requests(base_url: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let url = format!("{}/__admin/requests", base_url);
+    let response = ureq::delete(&url).call()?;
+    let code = response.status().as_u16();
+    if code == 200 || code == 204 {
+        Ok(())
+    } else {
+        Err(format!("Failed to clear the request journal: HTTP {}", code).into())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct VersionResponse {
+    version: Option<String>,
+}
+
+/// What a reachability probe learnt about a server.
+#[derive(Debug, Clone)]
+pub struct ServerInfo {
+    pub version: Option<String>,
+    pub mapping_count: Option<usize>,
+}
+
+/// Probes a server with a short timeout. `/__admin/health` only exists since
+/// WireMock 3, so older servers are asked for `/__admin/version` instead.
+pub fn get_server_info(
+    base_url: &str,
+    timeout: std::time::Duration,
+) -> Result<ServerInfo, Box<dyn std::error::Error>> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(timeout))
+        .build()
+        .into();
+    let mut response = match agent.get(format!("{}/__admin/health", base_url)).call() {
+        Err(ureq::Error::StatusCode(404)) => {
+            agent.get(format!("{}/__admin/version", base_url)).call()?
+        }
+        other => other?,
+    };
+    let version: VersionResponse = response.body_mut().read_json()?;
+    let mapping_count = agent
+        .get(format!("{}/__admin/mappings?limit=1", base_url))
+        .call()
+        .ok()
+        .and_then(|mut r| r.body_mut().read_json::<StubMappings>().ok())
+        .and_then(|m| m.meta)
+        .map(|m| m.total);
+    Ok(ServerInfo {
+        version: version.version,
+        mapping_count,
+    })
+}
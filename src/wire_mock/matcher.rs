@@ -0,0 +1,841 @@
+use crate::wire_mock::client::StubMapping;
+use base64::Engine;
+use regex::Regex;
+use serde_json::{Map, Value};
+
+/// WireMock's default stub priority.
+pub const DEFAULT_PRIORITY: i32 = 5;
+
+/// Scenarios begin in this state until a stub moves them on.
+pub const STARTED_STATE: &str = "Started";
+
+/// A request evaluated locally against stub mappings.
+#[derive(Clone, Debug, Default)]
+pub struct SimulatedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl SimulatedRequest {
+    pub fn path(&self) -> &str {
+        self.url.split_once('?').map_or(&self.url, |(path, _)| path)
+    }
+
+    pub fn query_parameters(&self) -> Vec<(String, String)> {
+        self.url
+            .split_once('?')
+            .map(|(_, query)| {
+                form_urlencoded::parse(query.as_bytes())
+                    .into_owned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn header_values(&self, name: &str) -> Vec<String> {
+        self.headers
+            .iter()
+            .filter(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.clone())
+            .collect()
+    }
+
+    fn cookie_values(&self, name: &str) -> Vec<String> {
+        self.header_values("Cookie")
+            .iter()
+            .flat_map(|header| header.split(';'))
+            .filter_map(|pair| pair.trim().split_once('='))
+            .filter(|(n, _)| *n == name)
+            .map(|(_, v)| v.to_string())
+            .collect()
+    }
+
+    fn form_values(&self, name: &str) -> Vec<String> {
+        form_urlencoded::parse(self.body.as_bytes())
+            .filter(|(n, _)| n == name)
+            .map(|(_, v)| v.into_owned())
+            .collect()
+    }
+}
+
+/// What applying one pattern offline found out.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Match,
+    Mismatch(String),
+    /// The pattern cannot be evaluated offline, so WireMock may decide either
+    /// way.
+    Unknown(String),
+}
+
+impl Outcome {
+    fn check(matches: bool, reason: impl FnOnce() -> String) -> Self {
+        if matches {
+            Outcome::Match
+        } else {
+            Outcome::Mismatch(reason())
+        }
+    }
+
+    pub fn is_match(&self) -> bool {
+        *self == Outcome::Match
+    }
+
+    /// Prefixes the reason with the field it is about.
+    fn labelled(self, label: &str) -> Self {
+        match self {
+            Outcome::Match => Outcome::Match,
+            Outcome::Mismatch(reason) => Outcome::Mismatch(format!("{}: {}", label, reason)),
+            Outcome::Unknown(reason) => Outcome::Unknown(format!("{}: {}", label, reason)),
+        }
+    }
+
+    /// Every outcome has to match. A single mismatch decides; otherwise an
+    /// unknown one leaves the result open.
+    fn all(outcomes: impl IntoIterator<Item = Outcome>) -> Self {
+        let mut unknown = None;
+        for outcome in outcomes {
+            match outcome {
+                Outcome::Match => {}
+                Outcome::Mismatch(reason) => return Outcome::Mismatch(reason),
+                Outcome::Unknown(reason) => {
+                    unknown.get_or_insert(reason);
+                }
+            }
+        }
+        unknown.map_or(Outcome::Match, Outcome::Unknown)
+    }
+
+    /// One outcome has to match. A single match decides; otherwise an unknown
+    /// one leaves the result open. A mismatch keeps the last reason.
+    fn any(outcomes: impl IntoIterator<Item = Outcome>) -> Self {
+        let mut unknown = None;
+        let mut mismatch = String::new();
+        for outcome in outcomes {
+            match outcome {
+                Outcome::Match => return Outcome::Match,
+                Outcome::Mismatch(reason) => mismatch = reason,
+                Outcome::Unknown(reason) => {
+                    unknown.get_or_insert(reason);
+                }
+            }
+        }
+        unknown.map_or(Outcome::Mismatch(mismatch), Outcome::Unknown)
+    }
+}
+
+/// Outcome of matching one stub against a simulated request.
+#[derive(Clone, Debug)]
+pub struct StubEvaluation {
+    /// Position of the stub in the list it was evaluated from.
+    pub index: usize,
+    /// One entry per field that did not match; empty when the stub matches.
+    pub mismatches: Vec<String>,
+    /// Fields that cannot be evaluated offline.
+    pub unknowns: Vec<String>,
+}
+
+impl StubEvaluation {
+    fn new(index: usize, outcomes: Vec<Outcome>) -> Self {
+        let mut evaluation = StubEvaluation {
+            index,
+            mismatches: vec![],
+            unknowns: vec![],
+        };
+        for outcome in outcomes {
+            match outcome {
+                Outcome::Match => {}
+                Outcome::Mismatch(reason) => evaluation.mismatches.push(reason),
+                Outcome::Unknown(reason) => evaluation.unknowns.push(reason),
+            }
+        }
+        evaluation
+    }
+
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty() && self.unknowns.is_empty()
+    }
+
+    /// Whether the stub matches on every field that can be checked offline,
+    /// but some cannot.
+    pub fn is_undecided(&self) -> bool {
+        self.mismatches.is_empty() && !self.unknowns.is_empty()
+    }
+}
+
+/// The stub WireMock would serve, as a position in `evaluations`: the first
+/// match in match order. Undecided stubs before it could be served instead.
+pub fn winner(evaluations: &[StubEvaluation]) -> Option<usize> {
+    evaluations.iter().position(StubEvaluation::is_match)
+}
+
+/// Indices of `stubs` in the order WireMock tries them: by priority, then
+/// most recently added first. The admin API lists newest stubs first, so list
+/// order breaks priority ties.
+pub fn match_order(stubs: &[StubMapping]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..stubs.len()).collect();
+    order.sort_by_key(|&i| stubs[i].priority.unwrap_or(DEFAULT_PRIORITY));
+    order
+}
+
+/// Evaluates every stub in match order. The first evaluation that matches is
+/// the stub WireMock would serve.
+pub fn simulate(stubs: &[StubMapping], request: &SimulatedRequest) -> Vec<StubEvaluation> {
+    match_order(stubs)
+        .into_iter()
+        .map(|index| StubEvaluation::new(index, evaluate(&stubs[index], request)))
+        .collect()
+}
+
+/// Lists the fields of `stub` that do not match `request`, or cannot be
+/// checked offline.
+fn evaluate(stub: &StubMapping, request: &SimulatedRequest) -> Vec<Outcome> {
+    let pattern = &stub.request;
+    let mut mismatches = vec![];
+
+    if pattern.method != "ANY" && !pattern.method.eq_ignore_ascii_case(&request.method) {
+        mismatches.push(Outcome::Mismatch(format!(
+            "method: expected {}, got {}",
+            pattern.method, request.method
+        )));
+    }
+
+    let mut path_parameters = Map::new();
+    if let Some(url) = &pattern.url
+        && *url != request.url
+    {
+        mismatches.push(Outcome::Mismatch(format!(
+            "url: expected {}, got {}",
+            url, request.url
+        )));
+    }
+    if let Some(url_path) = &pattern.url_path
+        && url_path != request.path()
+    {
+        mismatches.push(Outcome::Mismatch(format!(
+            "urlPath: expected {}, got {}",
+            url_path,
+            request.path()
+        )));
+    }
+    if let Some(url_pattern) = &pattern.url_pattern {
+        mismatches.push(full_match(url_pattern, &request.url).labelled("urlPattern"));
+    }
+    if let Some(url_path_pattern) = &pattern.url_path_pattern {
+        mismatches.push(full_match(url_path_pattern, request.path()).labelled("urlPathPattern"));
+    }
+    if let Some(template) = pattern.extra.get("urlPathTemplate").and_then(Value::as_str) {
+        match match_path_template(template, request.path()) {
+            Some(parameters) => path_parameters = parameters,
+            None => mismatches.push(Outcome::Mismatch(format!(
+                "urlPathTemplate: {} does not match {}",
+                template,
+                request.path()
+            ))),
+        }
+    }
+
+    for (field, label) in [
+        ("headers", "header"),
+        ("queryParameters", "query parameter"),
+        ("cookies", "cookie"),
+        ("formParameters", "form parameter"),
+        ("pathParameters", "path parameter"),
+    ] {
+        let Some(matchers) = pattern.extra.get(field).and_then(Value::as_object) else {
+            continue;
+        };
+        for (name, value_pattern) in matchers {
+            let values = match field {
+                "headers" => request.header_values(name),
+                "queryParameters" => request
+                    .query_parameters()
+                    .into_iter()
+                    .filter(|(n, _)| n == name)
+                    .map(|(_, v)| v)
+                    .collect(),
+                "cookies" => request.cookie_values(name),
+                "formParameters" => request.form_values(name),
+                _ => path_parameters
+                    .get(name)
+                    .and_then(Value::as_str)
+                    .map(|v| vec![v.to_string()])
+                    .unwrap_or_default(),
+            };
+            mismatches.push(
+                match_multi_value(value_pattern, &values).labelled(&format!("{} {}", label, name)),
+            );
+        }
+    }
+
+    if let Some(credentials) = pattern.extra.get("basicAuthCredentials") {
+        let expected = format!(
+            "Basic {}",
+            base64::engine::general_purpose::STANDARD.encode(format!(
+                "{}:{}",
+                credentials["username"].as_str().unwrap_or_default(),
+                credentials["password"].as_str().unwrap_or_default()
+            ))
+        );
+        if !request.header_values("Authorization").contains(&expected) {
+            mismatches.push(Outcome::Mismatch(
+                "basicAuthCredentials: credentials do not match".to_string(),
+            ));
+        }
+    }
+
+    if let Some(body_patterns) = pattern.extra.get("bodyPatterns").and_then(Value::as_array) {
+        for body_pattern in body_patterns {
+            mismatches.push(match_value(body_pattern, Some(&request.body)).labelled("body"));
+        }
+    }
+
+    for field in ["multipartPatterns", "customMatcher"] {
+        if pattern.extra.contains_key(field) {
+            mismatches.push(Outcome::Unknown(format!(
+                "{}: cannot be evaluated offline",
+                field
+            )));
+        }
+    }
+
+    if let Some(state) = stub
+        .extra
+        .get("requiredScenarioState")
+        .and_then(Value::as_str)
+        && state != STARTED_STATE
+    {
+        mismatches.push(Outcome::Unknown(format!(
+            "scenario: requires state {}, a fresh scenario is in {}",
+            state, STARTED_STATE
+        )));
+    }
+
+    mismatches.retain(|outcome| !outcome.is_match());
+    mismatches
+}
+
+/// Checks a Java-style regex, which must match the whole input.
+pub fn compile_full_match(pattern: &str) -> Result<Regex, regex::Error> {
+    Regex::new(&format!("^(?:{})$", pattern))
+}
+
+fn full_match(pattern: &str, actual: &str) -> Outcome {
+    match compile_full_match(pattern) {
+        Ok(regex) => Outcome::check(regex.is_match(actual), || {
+            format!("{} does not match {}", actual, pattern)
+        }),
+        Err(_) => Outcome::Mismatch(format!("invalid regex {}", pattern)),
+    }
+}
+
+fn match_path_template(template: &str, path: &str) -> Option<Map<String, Value>> {
+    let template_segments: Vec<&str> = template.split('/').collect();
+    let path_segments: Vec<&str> = path.split('/').collect();
+    if template_segments.len() != path_segments.len() {
+        return None;
+    }
+    let mut parameters = Map::new();
+    for (expected, actual) in template_segments.iter().zip(path_segments) {
+        match expected.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Some(name) if !actual.is_empty() => {
+                parameters.insert(name.to_string(), Value::String(actual.to_string()));
+            }
+            Some(_) => return None,
+            None if *expected != actual => return None,
+            None => {}
+        }
+    }
+    Some(parameters)
+}
+
+/// Matches a header, query, cookie or form pattern against all values of that
+/// field. A single-value pattern passes when any value matches.
+fn match_multi_value(pattern: &Value, values: &[String]) -> Outcome {
+    if let Some(expected) = pattern.get("hasExactly").and_then(Value::as_array) {
+        if expected.len() != values.len() {
+            return Outcome::Mismatch(format!(
+                "expected exactly {} values, got {}",
+                expected.len(),
+                values.len()
+            ));
+        }
+        return match_all_included(expected, values);
+    }
+    if let Some(expected) = pattern.get("includes").and_then(Value::as_array) {
+        return match_all_included(expected, values);
+    }
+    if values.is_empty() {
+        return match_value(pattern, None);
+    }
+    Outcome::any(values.iter().map(|value| match_value(pattern, Some(value))))
+}
+
+fn match_all_included(expected: &[Value], values: &[String]) -> Outcome {
+    Outcome::all(expected.iter().map(|pattern| {
+        match Outcome::any(values.iter().map(|value| match_value(pattern, Some(value)))) {
+            Outcome::Mismatch(_) => Outcome::Mismatch(format!("no value matches {}", pattern)),
+            other => other,
+        }
+    }))
+}
+
+/// Applies one WireMock content pattern, e.g. `{"equalTo": "x"}`, to a value
+/// that may be absent.
+pub fn match_value(pattern: &Value, actual: Option<&str>) -> Outcome {
+    let Some(pattern) = pattern.as_object() else {
+        return Outcome::Unknown(format!("unsupported pattern {}", pattern));
+    };
+
+    if let Some(absent) = pattern.get("absent").and_then(Value::as_bool) {
+        return match (absent, actual) {
+            (true, Some(value)) => Outcome::Mismatch(format!("expected absent, got {}", value)),
+            (false, None) => Outcome::Mismatch("expected present, was absent".to_string()),
+            _ => Outcome::Match,
+        };
+    }
+    if let Some(all) = pattern.get("and").and_then(Value::as_array) {
+        return Outcome::all(all.iter().map(|pattern| match_value(pattern, actual)));
+    }
+    if let Some(any) = pattern.get("or").and_then(Value::as_array) {
+        return match Outcome::any(any.iter().map(|pattern| match_value(pattern, actual))) {
+            Outcome::Mismatch(_) => {
+                Outcome::Mismatch(format!("none of {} alternatives match", any.len()))
+            }
+            other => other,
+        };
+    }
+
+    let Some(actual) = actual else {
+        return Outcome::Mismatch("absent".to_string());
+    };
+    let case_insensitive = pattern
+        .get("caseInsensitive")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    if let Some(expected) = pattern.get("equalTo").and_then(Value::as_str) {
+        let equal = if case_insensitive {
+            expected.eq_ignore_ascii_case(actual)
+        } else {
+            expected == actual
+        };
+        return Outcome::check(equal, || format!("expected {}, got {}", expected, actual));
+    }
+    if let Some(expected) = pattern.get("contains").and_then(Value::as_str) {
+        return Outcome::check(actual.contains(expected), || {
+            format!("{} does not contain {}", actual, expected)
+        });
+    }
+    if let Some(expected) = pattern.get("doesNotContain").and_then(Value::as_str) {
+        return Outcome::check(!actual.contains(expected), || {
+            format!("{} contains {}", actual, expected)
+        });
+    }
+    if let Some(regex) = pattern.get("matches").and_then(Value::as_str) {
+        return full_match(regex, actual);
+    }
+    if let Some(regex) = pattern.get("doesNotMatch").and_then(Value::as_str) {
+        return match compile_full_match(regex) {
+            Ok(compiled) => Outcome::check(!compiled.is_match(actual), || {
+                format!("{} matches {}", actual, regex)
+            }),
+            Err(_) => Outcome::Mismatch(format!("invalid regex {}", regex)),
+        };
+    }
+    if let Some(expected) = pattern.get("equalToJson") {
+        return match_equal_to_json(pattern, expected, actual);
+    }
+    if let Some(json_path) = pattern.get("matchesJsonPath") {
+        return match_json_path(json_path, actual);
+    }
+
+    let name = pattern.keys().next().map_or("(empty)", String::as_str);
+    Outcome::Unknown(format!("{} cannot be evaluated offline", name))
+}
+
+fn match_equal_to_json(pattern: &Map<String, Value>, expected: &Value, actual: &str) -> Outcome {
+    // The expected document may be given inline or as a JSON string
+    let expected = match expected {
+        Value::String(s) => match serde_json::from_str(s) {
+            Ok(expected) => expected,
+            Err(_) => return Outcome::Mismatch("equalToJson is not valid JSON".to_string()),
+        },
+        other => other.clone(),
+    };
+    let Ok(actual) = serde_json::from_str::<Value>(actual) else {
+        return Outcome::Mismatch("body is not valid JSON".to_string());
+    };
+    let flag = |name: &str| pattern.get(name).and_then(Value::as_bool).unwrap_or(false);
+    Outcome::check(
+        json_equal(
+            &expected,
+            &actual,
+            flag("ignoreArrayOrder"),
+            flag("ignoreExtraElements"),
+        ),
+        || "JSON body differs from equalToJson".to_string(),
+    )
+}
+
+fn json_equal(expected: &Value, actual: &Value, ignore_order: bool, ignore_extra: bool) -> bool {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            (ignore_extra || expected.len() == actual.len())
+                && expected.iter().all(|(key, value)| {
+                    actual
+                        .get(key)
+                        .is_some_and(|a| json_equal(value, a, ignore_order, ignore_extra))
+                })
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            if !ignore_extra && expected.len() != actual.len() {
+                return false;
+            }
+            if ignore_order {
+                let mut remaining: Vec<&Value> = actual.iter().collect();
+                expected.iter().all(|value| {
+                    match remaining
+                        .iter()
+                        .position(|a| json_equal(value, a, ignore_order, ignore_extra))
+                    {
+                        Some(position) => {
+                            remaining.remove(position);
+                            true
+                        }
+                        None => false,
+                    }
+                })
+            } else {
+                expected.len() <= actual.len()
+                    && expected
+                        .iter()
+                        .zip(actual)
+                        .all(|(e, a)| json_equal(e, a, ignore_order, ignore_extra))
+            }
+        }
+        _ => expected == actual,
+    }
+}
+
+fn match_json_path(json_path: &Value, actual: &str) -> Outcome {
+    let Ok(document) = serde_json::from_str::<Value>(actual) else {
+        return Outcome::Mismatch("body is not valid JSON".to_string());
+    };
+    let (expression, sub_pattern) = match json_path {
+        Value::String(expression) => (expression.as_str(), None),
+        Value::Object(object) => {
+            let Some(expression) = object.get("expression").and_then(Value::as_str) else {
+                return Outcome::Unknown("matchesJsonPath without expression".to_string());
+            };
+            let mut sub_pattern = object.clone();
+            sub_pattern.remove("expression");
+            (expression, Some(Value::Object(sub_pattern)))
+        }
+        _ => return Outcome::Unknown("unsupported matchesJsonPath".to_string()),
+    };
+    let Some(results) = select_json_path(&document, expression) else {
+        return Outcome::Unknown(format!(
+            "JSONPath {} cannot be evaluated offline",
+            expression
+        ));
+    };
+    let results: Vec<&Value> = results.into_iter().filter(|v| !v.is_null()).collect();
+    match sub_pattern {
+        None => Outcome::check(!results.is_empty(), || {
+            format!("{} selects nothing", expression)
+        }),
+        Some(sub_pattern) => {
+            let values: Vec<String> = results
+                .iter()
+                .map(|v| match v {
+                    Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                })
+                .collect();
+            match_multi_value(&sub_pattern, &values).labelled(expression)
+        }
+    }
+}
+
+/// Evaluates the subset of JSONPath made of `.key`, `['key']`, `[n]` and `*`
+/// steps. Returns `None` for anything else, e.g. filter expressions.
+fn select_json_path<'a>(document: &'a Value, expression: &str) -> Option<Vec<&'a Value>> {
+    let mut rest = expression.strip_prefix('$')?;
+    let mut current = vec![document];
+    while !rest.is_empty() {
+        let (step, remainder) = if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']')?;
+            (&after[..end], &after[end + 1..])
+        } else {
+            return None;
+        };
+        if step.is_empty() || step.starts_with('?') || step.contains(['(', ':', ',']) {
+            return None;
+        }
+        let key = step.trim_matches(|c| c == '\'' || c == '"');
+        current = current
+            .into_iter()
+            .flat_map(|value| -> Vec<&Value> {
+                match (key, value) {
+                    ("*", Value::Object(map)) => map.values().collect(),
+                    ("*", Value::Array(items)) => items.iter().collect(),
+                    (key, Value::Array(items)) => key
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|i| items.get(i))
+                        .into_iter()
+                        .collect(),
+                    (key, Value::Object(map)) => map.get(key).into_iter().collect(),
+                    _ => vec![],
+                }
+            })
+            .collect();
+        rest = remainder;
+    }
+    Some(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn check(pattern: Value, actual: Option<&str>) -> Outcome {
+        match_value(&pattern, actual)
+    }
+
+    fn is_mismatch(outcome: &Outcome) -> bool {
+        matches!(outcome, Outcome::Mismatch(_))
+    }
+
+    fn is_unknown(outcome: &Outcome) -> bool {
+        matches!(outcome, Outcome::Unknown(_))
+    }
+
+    fn stub(mapping: Value) -> StubMapping {
+        serde_json::from_value(mapping).unwrap()
+    }
+
+    fn get(url: &str) -> SimulatedRequest {
+        SimulatedRequest {
+            method: "GET".to_string(),
+            url: url.to_string(),
+            ..SimulatedRequest::default()
+        }
+    }
+
+    #[test]
+    fn equal_to_compares_exactly_unless_case_insensitive() {
+        assert!(check(json!({"equalTo": "abc"}), Some("abc")).is_match());
+        assert!(is_mismatch(&check(json!({"equalTo": "abc"}), Some("ABC"))));
+        assert!(check(
+            json!({"equalTo": "abc", "caseInsensitive": true}),
+            Some("ABC")
+        )
+        .is_match());
+        assert!(is_mismatch(&check(
+            json!({"equalTo": "abc", "caseInsensitive": true}),
+            Some("abd")
+        )));
+    }
+
+    #[test]
+    fn contains_and_does_not_contain() {
+        assert!(check(json!({"contains": "b"}), Some("abc")).is_match());
+        assert!(is_mismatch(&check(json!({"contains": "x"}), Some("abc"))));
+        assert!(check(json!({"doesNotContain": "x"}), Some("abc")).is_match());
+        assert!(is_mismatch(&check(
+            json!({"doesNotContain": "b"}),
+            Some("abc")
+        )));
+    }
+
+    #[test]
+    fn regexes_match_the_whole_value() {
+        assert!(check(json!({"matches": "a.c"}), Some("abc")).is_match());
+        assert!(is_mismatch(&check(json!({"matches": "b"}), Some("abc"))));
+        assert!(check(json!({"doesNotMatch": "b"}), Some("abc")).is_match());
+        assert!(is_mismatch(&check(
+            json!({"doesNotMatch": "a.*"}),
+            Some("abc")
+        )));
+        assert!(is_mismatch(&check(json!({"matches": "("}), Some("abc"))));
+    }
+
+    #[test]
+    fn equal_to_json_honours_its_flags() {
+        let body = r#"{"a": [1, 2], "b": true}"#;
+        assert!(check(json!({"equalToJson": {"b": true, "a": [1, 2]}}), Some(body)).is_match());
+        assert!(is_mismatch(&check(
+            json!({"equalToJson": {"a": [2, 1], "b": true}}),
+            Some(body)
+        )));
+        assert!(check(
+            json!({"equalToJson": {"a": [2, 1], "b": true}, "ignoreArrayOrder": true}),
+            Some(body)
+        )
+        .is_match());
+        assert!(is_mismatch(&check(
+            json!({"equalToJson": "{\"a\": [1, 2]}"}),
+            Some(body)
+        )));
+        assert!(check(
+            json!({"equalToJson": "{\"a\": [1, 2]}", "ignoreExtraElements": true}),
+            Some(body)
+        )
+        .is_match());
+        assert!(is_mismatch(&check(
+            json!({"equalToJson": {}}),
+            Some("not json")
+        )));
+    }
+
+    #[test]
+    fn matches_json_path_with_and_without_a_sub_pattern() {
+        let body = r#"{"items": [{"id": "x"}, {"id": "y"}]}"#;
+        assert!(check(json!({"matchesJsonPath": "$.items[1].id"}), Some(body)).is_match());
+        assert!(is_mismatch(&check(
+            json!({"matchesJsonPath": "$.missing"}),
+            Some(body)
+        )));
+        assert!(check(
+            json!({"matchesJsonPath": {"expression": "$.items[*].id", "equalTo": "y"}}),
+            Some(body)
+        )
+        .is_match());
+        assert!(is_mismatch(&check(
+            json!({"matchesJsonPath": {"expression": "$.items[*].id", "equalTo": "z"}}),
+            Some(body)
+        )));
+        assert!(is_unknown(&check(
+            json!({"matchesJsonPath": "$.items[?(@.id == 'x')]"}),
+            Some(body)
+        )));
+    }
+
+    #[test]
+    fn operators_not_evaluated_offline_are_unknown() {
+        assert!(is_unknown(&check(
+            json!({"equalToXml": "<a/>"}),
+            Some("<a/>")
+        )));
+        assert!(is_unknown(&check(json!("abc"), Some("abc"))));
+    }
+
+    #[test]
+    fn absent_and_present() {
+        assert!(check(json!({"absent": true}), None).is_match());
+        assert!(is_mismatch(&check(json!({"absent": true}), Some("x"))));
+        assert!(check(json!({"absent": false}), Some("x")).is_match());
+        assert!(is_mismatch(&check(json!({"absent": false}), None)));
+        assert!(is_mismatch(&check(json!({"equalTo": "x"}), None)));
+    }
+
+    #[test]
+    fn and_needs_every_pattern_and_a_mismatch_beats_an_unknown() {
+        let both = json!({"and": [{"contains": "a"}, {"contains": "b"}]});
+        assert!(check(both.clone(), Some("ab")).is_match());
+        assert!(is_mismatch(&check(both, Some("a"))));
+        assert!(is_unknown(&check(
+            json!({"and": [{"equalToXml": "<a/>"}, {"contains": "a"}]}),
+            Some("a")
+        )));
+        assert!(is_mismatch(&check(
+            json!({"and": [{"equalToXml": "<a/>"}, {"contains": "b"}]}),
+            Some("a")
+        )));
+    }
+
+    #[test]
+    fn or_needs_one_pattern_and_a_match_beats_an_unknown() {
+        let either = json!({"or": [{"equalTo": "a"}, {"equalTo": "b"}]});
+        assert!(check(either.clone(), Some("b")).is_match());
+        assert!(is_mismatch(&check(either, Some("c"))));
+        assert!(check(
+            json!({"or": [{"equalToXml": "<a/>"}, {"equalTo": "a"}]}),
+            Some("a")
+        )
+        .is_match());
+        assert!(is_unknown(&check(
+            json!({"or": [{"equalToXml": "<a/>"}, {"equalTo": "b"}]}),
+            Some("a")
+        )));
+    }
+
+    #[test]
+    fn multi_value_patterns() {
+        let values = ["a".to_string(), "b".to_string()];
+        assert!(match_multi_value(&json!({"equalTo": "b"}), &values).is_match());
+        assert!(match_multi_value(
+            &json!({"includes": [{"equalTo": "a"}, {"equalTo": "b"}]}),
+            &values
+        )
+        .is_match());
+        assert!(is_mismatch(&match_multi_value(
+            &json!({"includes": [{"equalTo": "c"}]}),
+            &values
+        )));
+        assert!(is_mismatch(&match_multi_value(
+            &json!({"hasExactly": [{"equalTo": "a"}]}),
+            &values
+        )));
+        assert!(match_multi_value(&json!({"absent": true}), &[]).is_match());
+    }
+
+    #[test]
+    fn winner_is_the_first_match_by_priority_then_newest() {
+        let stubs = vec![
+            stub(json!({"id": "newest", "request": {"urlPath": "/a"}, "response": {}})),
+            stub(json!({"id": "older", "request": {"urlPath": "/a"}, "response": {}})),
+            stub(json!({
+                "id": "important",
+                "priority": 1,
+                "request": {"urlPath": "/a", "method": "POST"},
+                "response": {}
+            })),
+        ];
+        let simulation = simulate(&stubs, &get("/a?x=1"));
+        assert_eq!(simulation[0].index, 2);
+        assert!(!simulation[0].mismatches.is_empty());
+        let winner = winner(&simulation).unwrap();
+        assert_eq!(stubs[simulation[winner].index].id, "newest");
+    }
+
+    #[test]
+    fn undecided_stubs_do_not_win() {
+        let stubs = vec![
+            stub(json!({
+                "id": "scenario",
+                "priority": 1,
+                "scenarioName": "flow",
+                "requiredScenarioState": "Paid",
+                "request": {"urlPath": "/a"},
+                "response": {}
+            })),
+            stub(json!({"id": "plain", "request": {"urlPath": "/a"}, "response": {}})),
+        ];
+        let simulation = simulate(&stubs, &get("/a"));
+        assert!(simulation[0].is_undecided());
+        assert_eq!(simulation[0].unknowns.len(), 1);
+        assert_eq!(winner(&simulation), Some(1));
+    }
+
+    #[test]
+    fn a_mismatch_decides_a_stub_with_unknown_fields() {
+        let stubs = vec![stub(json!({
+            "id": "custom",
+            "request": {"urlPath": "/b", "customMatcher": {"name": "x"}},
+            "response": {}
+        }))];
+        let simulation = simulate(&stubs, &get("/a"));
+        assert!(!simulation[0].is_match());
+        assert!(!simulation[0].is_undecided());
+        assert_eq!(winner(&simulation), None);
+    }
+}